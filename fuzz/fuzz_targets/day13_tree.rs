@@ -0,0 +1,7 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|packet: &str| {
+    let _ = aoc_2022::y2022::day13::parse_tree(packet);
+});
@@ -0,0 +1,14 @@
+#!/bin/sh
+# Seeds the corpus of every fuzz target from the puzzle inputs in ../inputs/2022.
+#
+#   ./seed_corpus.sh && cargo +nightly fuzz run day05
+set -e
+cd "$(dirname "$0")"
+for input in ../inputs/2022/day*.txt; do
+    day=$(basename "$input" .txt)
+    mkdir -p "corpus/$day"
+    cp "$input" "corpus/$day/$day"
+done
+# The packet parser gets one packet per file.
+mkdir -p corpus/day13_tree
+grep -v '^$' ../inputs/2022/day13.txt | head -n 50 | awk '{ printf "%s", $0 > ("corpus/day13_tree/packet" NR) }'
@@ -0,0 +1,70 @@
+import pathlib
+import unittest
+
+import aoc_2022
+
+ROOT = pathlib.Path(__file__).resolve().parents[2]
+
+
+class SolverTest(unittest.TestCase):
+    def test_solvers_are_registered(self):
+        solvers = aoc_2022.solvers()
+        self.assertIn((2022, 1, 1), solvers)
+        self.assertIn((2022, 25, 1), solvers)
+        self.assertNotIn((2022, 25, 2), solvers)
+
+    def test_number_answer(self):
+        self.assertEqual(aoc_2022.solve(2022, 1, 1, "1\n2\n\n4\n"), 4)
+        self.assertEqual(aoc_2022.solve(2022, 1, 2, "1\n2\n\n4\n\n5\n\n6\n"), 15)
+
+    def test_text_answer(self):
+        self.assertEqual(aoc_2022.solve(2022, 25, 1, "1=\n1-\n"), "12")
+
+    def test_real_input(self):
+        day01 = (ROOT / aoc_2022.input_path(2022, 1)).read_text()
+        self.assertIsInstance(aoc_2022.solve(2022, 1, 1, day01), int)
+        day05 = (ROOT / aoc_2022.input_path(2022, 5)).read_text()
+        self.assertIsInstance(aoc_2022.solve(2022, 5, 1, day05), str)
+
+    def test_unknown_solver(self):
+        with self.assertRaises(ValueError):
+            aoc_2022.solve(2022, 26, 1, "")
+        with self.assertRaises(ValueError):
+            aoc_2022.solve(2022, 25, 2, "")
+        with self.assertRaises(ValueError):
+            aoc_2022.solve(2021, 1, 1, "")
+
+
+class SnafuTest(unittest.TestCase):
+    def test_round_trip(self):
+        for value, snafu in [(3, "1="), (2022, "1=11-2"), (314159265, "1121-1110-1=0")]:
+            self.assertEqual(int(aoc_2022.Snafu.parse(snafu)), value)
+            self.assertEqual(str(aoc_2022.Snafu(value)), snafu)
+
+    def test_add(self):
+        self.assertEqual(aoc_2022.Snafu(3) + aoc_2022.Snafu(4), aoc_2022.Snafu(7))
+
+    def test_invalid(self):
+        with self.assertRaises(ValueError):
+            aoc_2022.Snafu.parse("12a")
+
+
+class TreeTest(unittest.TestCase):
+    def test_ordering(self):
+        left = aoc_2022.Tree.parse("[[1],[2,3,4]]")
+        right = aoc_2022.Tree.parse("[[1],4]")
+        self.assertLess(left, right)
+        self.assertEqual(left.compare(right), -1)
+        self.assertEqual(aoc_2022.Tree.parse("[1]"), aoc_2022.Tree.parse("[[1]]"))
+
+    def test_sorting(self):
+        packets = [aoc_2022.Tree.parse(p) for p in ["[[6]]", "[]", "[[2]]", "[1,1,3]"]]
+        self.assertEqual([str(p) for p in sorted(packets)], ["[]", "[1,1,3]", "[[2]]", "[[6]]"])
+
+    def test_invalid(self):
+        with self.assertRaises(ValueError):
+            aoc_2022.Tree.parse("[1,2")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,75 @@
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+use crate::alloc_stats::{self, format_bytes, AllocStats};
+use crate::control::Control;
+use crate::input;
+use crate::solver::{Answer, Puzzle};
+
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    pub allocations: Option<AllocStats>,
+}
+
+pub fn measure(puzzle: &Puzzle) -> Vec<Measurement> {
+    let input = input::load(puzzle.input);
+    let mut measurements = vec![];
+    for (index, part) in puzzle.parts.iter().enumerate() {
+        let start = Instant::now();
+        let (answer, allocations) = alloc_stats::measure(|| part(&input, &Control::default()));
+        let answer = answer.expect("solver without cancel token was cancelled");
+        measurements.push(Measurement {
+            year: puzzle.year,
+            day: puzzle.day,
+            part: index as u8 + 1,
+            answer,
+            elapsed: start.elapsed(),
+            allocations,
+        });
+    }
+    measurements
+}
+
+pub fn report(measurements: &[Measurement]) -> String {
+    let mut out = String::new();
+    let with_allocations = measurements.iter().any(|m| m.allocations.is_some());
+    write!(
+        out,
+        "{:>4} {:>3} {:>4} {:>12}",
+        "year", "day", "part", "time"
+    )
+    .unwrap();
+    if with_allocations {
+        write!(out, " {:>10} {:>12} {:>12}", "allocs", "allocated", "peak").unwrap();
+    }
+    writeln!(out).unwrap();
+    for m in measurements {
+        write!(
+            out,
+            "{:>4} {:>3} {:>4} {:>12}",
+            m.year,
+            m.day,
+            m.part,
+            format!("{:.2?}", m.elapsed)
+        )
+        .unwrap();
+        if let Some(stats) = m.allocations {
+            write!(
+                out,
+                " {:>10} {:>12} {:>12}",
+                stats.allocations,
+                format_bytes(stats.bytes),
+                format_bytes(stats.peak)
+            )
+            .unwrap();
+        }
+        writeln!(out).unwrap();
+    }
+    let total: Duration = measurements.iter().map(|m| m.elapsed).sum();
+    writeln!(out, "total {:.2?}", total).unwrap();
+    out
+}
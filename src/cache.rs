@@ -0,0 +1,91 @@
+//! On-disk cache of answers, so re-running a part on an unchanged input with an unchanged solver
+//! returns immediately.
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::input;
+use crate::solver::{Answer, Puzzle};
+
+pub const DIR: &str = ".cache";
+
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Cache::new(DIR)
+    }
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    /// Answers are keyed by year, day, part, a fingerprint of the input and the solver's version.
+    fn path(&self, puzzle: &Puzzle, part: u8, input: &str) -> PathBuf {
+        self.dir.join(format!(
+            "{}_day{:02}_part{part}_{:016x}_v{}.txt",
+            puzzle.year,
+            puzzle.day,
+            input::fingerprint(input),
+            puzzle.version
+        ))
+    }
+
+    pub fn get(&self, puzzle: &Puzzle, part: u8, input: &str) -> Option<Answer> {
+        let line = fs::read_to_string(self.path(puzzle, part, input)).ok()?;
+        Some(Answer::from_line(line.trim_end_matches('\n')))
+    }
+
+    pub fn put(&self, puzzle: &Puzzle, part: u8, input: &str, answer: &Answer) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(
+            self.path(puzzle, part, input),
+            format!("{}\n", answer.to_line()),
+        )
+    }
+
+    /// Removes all cached answers, returning how many there were.
+    pub fn clear(&self) -> io::Result<usize> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e),
+        };
+        let mut removed = 0;
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|e| e == "txt") {
+                fs::remove_file(path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use crate::cache::*;
+    use crate::solver;
+
+    #[test]
+    fn it_works() {
+        let cache =
+            Cache::new(env::temp_dir().join(format!("aoc-2022-cache-{}", std::process::id())));
+        let puzzle = solver::puzzle(2022, 10).unwrap();
+        let picture = Answer::Text("#..#\n.##.".to_string());
+        assert_eq!(cache.get(puzzle, 2, "noop\n"), None);
+        cache.put(puzzle, 2, "noop\n", &picture).unwrap();
+        assert_eq!(cache.get(puzzle, 2, "noop\n"), Some(picture));
+        assert_eq!(cache.get(puzzle, 2, "addx 1\n"), None);
+        assert_eq!(cache.get(puzzle, 1, "noop\n"), None);
+        assert_eq!(cache.clear().unwrap(), 1);
+        assert_eq!(cache.clear().unwrap(), 0);
+    }
+}
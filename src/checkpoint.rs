@@ -0,0 +1,182 @@
+//! Saving the state of long-running searches to disk, so an interrupted run can be resumed.
+//!
+//! A checkpoint file starts with a header naming the year, the day, the part and a fingerprint of the
+//! input, followed by whatever the search wrote. Searches only see [`Control::resume`] and
+//! [`Control::check_with`]; the file handling lives in [`Checkpoints`].
+//!
+//! [`Control::resume`]: crate::control::Control::resume
+//! [`Control::check_with`]: crate::control::Control::check_with
+use std::fmt::{Display, Write as _};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::{FromStr, Lines};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::input;
+
+pub const DIR: &str = "checkpoints";
+
+/// How often a running search writes its state.
+const INTERVAL: Duration = Duration::from_secs(10);
+
+/// State of a search that can be written to a checkpoint and read back.
+pub trait Checkpoint: Sized {
+    fn write(&self, out: &mut Writer);
+
+    /// Returns `None` if the text was not written by [`Checkpoint::write`] of this type.
+    fn read(input: &mut Reader) -> Option<Self>;
+}
+
+#[derive(Default)]
+pub struct Writer(String);
+
+impl Writer {
+    pub fn field(&mut self, key: &str, value: impl Display) {
+        writeln!(self.0, "{key} {value}").unwrap();
+    }
+
+    /// Writes a list as its length followed by one line per item.
+    pub fn list<T: Display>(&mut self, key: &str, items: impl ExactSizeIterator<Item = T>) {
+        self.field(key, items.len());
+        for item in items {
+            writeln!(self.0, "{item}").unwrap();
+        }
+    }
+}
+
+pub struct Reader<'a>(Lines<'a>);
+
+impl<'a> Reader<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Reader(text.lines())
+    }
+
+    pub fn field<T: FromStr>(&mut self, key: &str) -> Option<T> {
+        let (k, value) = self.0.next()?.split_once(' ')?;
+        if k != key {
+            return None;
+        }
+        value.parse().ok()
+    }
+
+    pub fn list(&mut self, key: &str) -> Option<Vec<&'a str>> {
+        let len: usize = self.field(key)?;
+        let items: Vec<_> = self.0.by_ref().take(len).collect();
+        (items.len() == len).then_some(items)
+    }
+}
+
+/// The checkpoint file of one run of a part on one input.
+pub struct Checkpoints {
+    path: PathBuf,
+    header: String,
+    resume: bool,
+    last_save: Mutex<Instant>,
+}
+
+impl Checkpoints {
+    /// With `resume` set, a search continues from a matching checkpoint file if there is one.
+    /// Otherwise it starts over and overwrites the file on its first save.
+    pub fn new(year: u16, day: u8, part: u8, input: &str, resume: bool) -> Self {
+        Checkpoints {
+            path: Path::new(DIR).join(format!("{year}_day{day:02}_part{part}.txt")),
+            header: format!(
+                "aoc-2022 checkpoint year {year} day {day} part {part} input {:016x}",
+                input::fingerprint(input)
+            ),
+            resume,
+            last_save: Mutex::new(Instant::now()),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether the file exists and was written for the same year, day, part and input.
+    pub fn matches(&self) -> bool {
+        self.contents().is_some()
+    }
+
+    fn contents(&self) -> Option<String> {
+        let text = fs::read_to_string(&self.path).ok()?;
+        let (header, body) = text.split_once('\n')?;
+        (header == self.header).then(|| body.to_string())
+    }
+
+    pub fn load<T: Checkpoint>(&self) -> Option<T> {
+        if !self.resume {
+            return None;
+        }
+        T::read(&mut Reader::new(&self.contents()?))
+    }
+
+    /// Saves `state` if the last save is longer ago than the checkpoint interval.
+    pub fn save_if_due<T: Checkpoint>(&self, state: &T) -> io::Result<()> {
+        if self.last_save.lock().unwrap().elapsed() < INTERVAL {
+            return Ok(());
+        }
+        self.save(state)
+    }
+
+    pub fn save<T: Checkpoint>(&self, state: &T) -> io::Result<()> {
+        let mut out = Writer::default();
+        state.write(&mut out);
+        fs::create_dir_all(DIR)?;
+        // Write to a temporary file first so a run killed mid-save keeps the previous checkpoint.
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, format!("{}\n{}", self.header, out.0))?;
+        fs::rename(tmp, &self.path)?;
+        *self.last_save.lock().unwrap() = Instant::now();
+        Ok(())
+    }
+
+    /// Removes the checkpoint once the search has finished.
+    pub fn clear(&self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::checkpoint::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Search {
+        best: i32,
+        frontier: Vec<String>,
+    }
+
+    impl Checkpoint for Search {
+        fn write(&self, out: &mut Writer) {
+            out.field("best", self.best);
+            out.list("frontier", self.frontier.iter());
+        }
+
+        fn read(input: &mut Reader) -> Option<Self> {
+            Some(Search {
+                best: input.field("best")?,
+                frontier: input
+                    .list("frontier")?
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+            })
+        }
+    }
+
+    #[test]
+    fn it_works() {
+        let search = Search {
+            best: 7,
+            frontier: vec!["AA BB".to_string(), String::new()],
+        };
+        let mut out = Writer::default();
+        search.write(&mut out);
+        assert_eq!(out.0, "best 7\nfrontier 2\nAA BB\n\n");
+        assert_eq!(Search::read(&mut Reader::new(&out.0)), Some(search));
+        assert_eq!(Search::read(&mut Reader::new("best 7\nfrontier 3\n")), None);
+    }
+}
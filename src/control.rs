@@ -0,0 +1,157 @@
+//! Cooperative cancellation, progress reporting, checkpoints and event logs for the solvers.
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+
+use crate::checkpoint::{Checkpoint, Checkpoints};
+use crate::events::EventLog;
+
+/// Shared flag that asks a running solver to stop at its next check.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        CancelToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Returned by a solver that noticed its [`CancelToken`] was cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// What the solver is currently doing, e.g. "blueprints" or "rounds".
+    pub stage: &'static str,
+    pub done: u64,
+    /// Known upper bound of `done`, if the search has one.
+    pub total: Option<u64>,
+}
+
+type ProgressFn = dyn Fn(Progress) + Send + Sync;
+
+/// Handed to solvers that support cancellation, bundling the token with a progress callback, the
+/// checkpoint file and the event log, if any.
+#[derive(Clone, Default)]
+pub struct Control {
+    cancel: CancelToken,
+    progress: Option<Arc<ProgressFn>>,
+    checkpoints: Option<Arc<Checkpoints>>,
+    events: Option<EventLog>,
+}
+
+impl Control {
+    pub fn new(cancel: CancelToken, progress: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        Control {
+            cancel,
+            progress: Some(Arc::new(progress)),
+            checkpoints: None,
+            events: None,
+        }
+    }
+
+    pub fn with_checkpoints(mut self, checkpoints: Arc<Checkpoints>) -> Self {
+        self.checkpoints = Some(checkpoints);
+        self
+    }
+
+    pub fn with_events(mut self, events: EventLog) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// The log to write events to, if they are wanted. Solvers only build events when this is
+    /// `Some`, so logging costs nothing otherwise.
+    pub fn events(&self) -> Option<&EventLog> {
+        self.events.as_ref()
+    }
+
+    /// Returns `Err(Cancelled)` once cancellation was requested.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.cancel.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Like [`Control::check`], but also saves `state` periodically and when cancelled, so a
+    /// later run can pick it up through [`Control::resume`].
+    pub fn check_with<T: Checkpoint>(&self, state: &T) -> Result<(), Cancelled> {
+        let Some(checkpoints) = &self.checkpoints else {
+            return self.check();
+        };
+        let cancelled = self.check();
+        let saved = if cancelled.is_err() {
+            checkpoints.save(state)
+        } else {
+            checkpoints.save_if_due(state)
+        };
+        if let Err(e) = saved {
+            eprintln!(
+                "could not save checkpoint {}: {e}",
+                checkpoints.path().display()
+            );
+        }
+        cancelled
+    }
+
+    /// The state saved by an earlier run of the same part on the same input, if it should be
+    /// resumed.
+    pub fn resume<T: Checkpoint>(&self) -> Option<T> {
+        self.checkpoints.as_ref()?.load()
+    }
+
+    pub fn report(&self, stage: &'static str, done: u64, total: Option<u64>) {
+        if let Some(progress) = &self.progress {
+            progress(Progress { stage, done, total })
+        }
+    }
+
+    /// Creates a thread-safe counter for a loop with many cheap iterations.
+    pub fn counter(&self, stage: &'static str, total: Option<u64>) -> Counter<'_> {
+        Counter {
+            control: self,
+            stage,
+            total,
+            done: AtomicU64::new(0),
+        }
+    }
+}
+
+/// Counts iterations of a search, checking for cancellation on every tick and reporting
+/// progress on every 1024th.
+pub struct Counter<'a> {
+    control: &'a Control,
+    stage: &'static str,
+    total: Option<u64>,
+    done: AtomicU64,
+}
+
+impl Counter<'_> {
+    pub fn tick(&self) -> Result<(), Cancelled> {
+        self.control.check()?;
+        let done = self.done.fetch_add(1, Ordering::Relaxed) + 1;
+        if done.is_multiple_of(1024) || Some(done) == self.total {
+            self.control.report(self.stage, done, self.total);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+//! Machine-readable logs of what a solver did, like every rock landing in day 17, written as
+//! JSON Lines: one JSON object per line and event.
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Number(String),
+    Text(String),
+    List(Vec<Value>),
+}
+
+macro_rules! number_value {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Value {
+            fn from(n: $t) -> Self {
+                Value::Number(n.to_string())
+            }
+        })*
+    };
+}
+
+number_value!(i32, i64, u8, u32, u64, usize);
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::Text(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::Text(s)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(values: Vec<T>) -> Self {
+        Value::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl Value {
+    fn write_json(&self, out: &mut String) {
+        match self {
+            Value::Number(n) => out.push_str(n),
+            Value::Text(s) => {
+                out.push('"');
+                for c in s.chars() {
+                    match c {
+                        '"' => out.push_str("\\\""),
+                        '\\' => out.push_str("\\\\"),
+                        '\n' => out.push_str("\\n"),
+                        c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+                        c => out.push(c),
+                    }
+                }
+                out.push('"');
+            }
+            Value::List(values) => {
+                out.push('[');
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    value.write_json(out);
+                }
+                out.push(']');
+            }
+        }
+    }
+}
+
+/// Something that happened, named like `rock_landed`, with the fields describing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    name: &'static str,
+    fields: Vec<(&'static str, Value)>,
+}
+
+impl Event {
+    pub fn new(name: &'static str) -> Self {
+        Event {
+            name,
+            fields: vec![],
+        }
+    }
+
+    pub fn with(mut self, key: &'static str, value: impl Into<Value>) -> Self {
+        self.fields.push((key, value.into()));
+        self
+    }
+
+    /// The event as one line of JSON, starting with the year, day and part it belongs to.
+    pub fn to_json(&self, year: u16, day: u8, part: u8) -> String {
+        let mut out = format!("{{\"year\":{year},\"day\":{day},\"part\":{part},\"event\":");
+        Value::from(self.name).write_json(&mut out);
+        for (key, value) in &self.fields {
+            out.push(',');
+            Value::from(*key).write_json(&mut out);
+            out.push(':');
+            value.write_json(&mut out);
+        }
+        out.push('}');
+        out
+    }
+}
+
+struct Sink {
+    out: Box<dyn Write + Send>,
+    /// The first write that failed; later events are dropped.
+    error: Option<io::Error>,
+}
+
+/// Where events go. Clones write to the same place, so one log can be shared by several parts
+/// through [`EventLog::for_part`].
+#[derive(Clone)]
+pub struct EventLog {
+    sink: Arc<Mutex<Sink>>,
+    year: u16,
+    day: u8,
+    part: u8,
+}
+
+impl EventLog {
+    pub fn new(out: impl Write + Send + 'static) -> Self {
+        EventLog {
+            sink: Arc::new(Mutex::new(Sink {
+                out: Box::new(out),
+                error: None,
+            })),
+            year: 0,
+            day: 0,
+            part: 0,
+        }
+    }
+
+    pub fn create(path: &Path) -> io::Result<Self> {
+        Ok(EventLog::new(BufWriter::new(File::create(path)?)))
+    }
+
+    /// The same log, tagging its events with `year`, `day` and `part`.
+    pub fn for_part(&self, year: u16, day: u8, part: u8) -> Self {
+        EventLog {
+            sink: self.sink.clone(),
+            year,
+            day,
+            part,
+        }
+    }
+
+    pub fn write(&self, event: &Event) {
+        let mut sink = self.sink.lock().unwrap();
+        if sink.error.is_none() {
+            let line = event.to_json(self.year, self.day, self.part);
+            if let Err(e) = writeln!(sink.out, "{line}") {
+                sink.error = Some(e);
+            }
+        }
+    }
+
+    /// Flushes the log, returning the first error writing it ran into.
+    pub fn finish(&self) -> io::Result<()> {
+        let mut sink = self.sink.lock().unwrap();
+        match sink.error.take() {
+            Some(e) => Err(e),
+            None => sink.out.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::events::*;
+
+    /// A writer whose output stays readable after the log took it.
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn it_works() {
+        let out = Shared::default();
+        let log = EventLog::new(out.clone()).for_part(2022, 17, 1);
+        log.write(
+            &Event::new("rock_landed")
+                .with("shape", "plus")
+                .with("at", vec![2, -3])
+                .with("note", "a \"quoted\"\nline"),
+        );
+        log.finish().unwrap();
+        assert_eq!(
+            String::from_utf8(out.0.lock().unwrap().clone()).unwrap(),
+            "{\"year\":2022,\"day\":17,\"part\":1,\"event\":\"rock_landed\",\"shape\":\"plus\",\"at\":[2,-3],\
+             \"note\":\"a \\\"quoted\\\"\\nline\"}\n"
+        );
+    }
+}
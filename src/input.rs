@@ -0,0 +1,94 @@
+//! Loading and splitting puzzle inputs.
+//!
+//! Every input goes through [`normalise`] before a parser sees it, so parsers can rely on `\n`
+//! line endings, no byte order mark, no trailing whitespace on any line and exactly one newline
+//! at the end of a non-empty input.
+use std::fs::{self, File};
+use std::io::BufReader;
+
+pub fn load(path: &str) -> String {
+    let raw = fs::read_to_string(path).expect("Should have been able to read the file");
+    normalise(&raw)
+}
+
+/// Opens an input for solvers that stream it line by line instead of loading it whole. Unlike
+/// [`load`], this does not normalise the input.
+pub fn open(path: &str) -> BufReader<File> {
+    BufReader::new(File::open(path).expect("Should have been able to open the file"))
+}
+
+pub fn normalise(raw: &str) -> String {
+    let raw = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+    let raw = raw.replace("\r\n", "\n").replace('\r', "\n");
+    let mut result = String::with_capacity(raw.len() + 1);
+    for line in raw.lines() {
+        result.push_str(line.trim_end());
+        result.push('\n');
+    }
+    result.truncate(result.trim_end().len());
+    if !result.is_empty() {
+        result.push('\n');
+    }
+    result
+}
+
+/// Splits an input into groups of lines separated by one or more blank lines.
+///
+/// Leading whitespace of the first line of a group is kept, so indented drawings (like the
+/// crate stacks of day 5) survive.
+pub fn paragraphs(input: &str) -> Vec<&str> {
+    let mut result = vec![];
+    let mut start = None;
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        let blank = line.trim().is_empty();
+        match start {
+            None if !blank => start = Some(offset),
+            Some(s) if blank => {
+                result.push(input[s..offset].trim_end_matches('\n'));
+                start = None;
+            }
+            _ => {}
+        }
+        offset += line.len();
+    }
+    if let Some(s) = start {
+        result.push(input[s..].trim_end_matches('\n'));
+    }
+    result
+}
+
+/// Iterates over all lines of an input that contain anything but whitespace.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().filter(|line| !line.trim().is_empty())
+}
+
+/// FNV-1a hash of an input, used to tell whether saved state belongs to the same input.
+pub fn fingerprint(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::*;
+    use crate::solver::{solve, Answer};
+
+    #[test]
+    fn it_works() {
+        assert_eq!(normalise("\u{feff}1\r\n2  \r\n\r\n3"), "1\n2\n\n3\n");
+        assert_eq!(normalise("1\r2\n\n\n"), "1\n2\n");
+        assert_eq!(normalise(" \n\n"), "");
+
+        let input = "    [D]\n[N] [C]\n 1   2\n \n\n\nmove 1 from 2 to 1\n";
+        assert_eq!(
+            paragraphs(input),
+            vec!["    [D]\n[N] [C]\n 1   2", "move 1 from 2 to 1"]
+        );
+        assert_eq!(lines("a\n\n  \nb\n").collect::<Vec<_>>(), vec!["a", "b"]);
+
+        let crlf = "\u{feff}[1,1,3,1,1]\r\n[1,1,5,1,1]\r\n\r\n\r\n[[1],[2,3,4]]\r\n[[1],4]";
+        assert_eq!(solve(2022, 13, 1, crlf), Some(Answer::Number(3)));
+    }
+}
@@ -0,0 +1,72 @@
+//! Structural statistics about puzzle inputs, and warnings when an input breaks an assumption
+//! a solver makes.
+use std::fmt::{self, Display};
+
+use crate::*;
+
+#[derive(Debug, Default)]
+pub struct Inspection {
+    facts: Vec<(&'static str, String)>,
+    warnings: Vec<String>,
+}
+
+impl Inspection {
+    pub fn fact(&mut self, name: &'static str, value: impl Display) {
+        self.facts.push((name, value.to_string()));
+    }
+
+    pub fn warn(&mut self, warning: impl Into<String>) {
+        self.warnings.push(warning.into());
+    }
+
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+}
+
+impl Display for Inspection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.facts.iter().map(|(name, _)| name.len()).max();
+        for (name, value) in &self.facts {
+            let width = width.unwrap_or_default();
+            // Multi-line values like the net of day 22 start on their own line.
+            if value.contains('\n') {
+                writeln!(f, "{name}:\n{value}")?;
+            } else {
+                writeln!(f, "{name:<width$}  {value}")?;
+            }
+        }
+        for warning in &self.warnings {
+            writeln!(f, "warning: {warning}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The inspection of a day, if it has one: the shape of the input its solver depends on, or a
+/// report analysing the input.
+pub fn inspector(year: u16, day: u8) -> Option<fn(&str) -> Inspection> {
+    match year {
+        y2022::YEAR => y2022::inspector(day),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inspect::*;
+
+    #[test]
+    fn it_works() {
+        let mut inspection = Inspection::default();
+        inspection.fact("valves", 10);
+        inspection.fact("with flow", 6);
+        inspection.warn("no valve AA");
+        assert_eq!(
+            inspection.to_string(),
+            "valves     10\nwith flow  6\nwarning: no valve AA\n"
+        );
+        assert!(inspector(1970, 1).is_none());
+        assert!(inspector(2022, 16).is_some());
+    }
+}
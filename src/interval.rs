@@ -0,0 +1,271 @@
+//! Closed integer intervals and sets of them, like the section assignments of 2022 day 4 or the
+//! positions a sensor of 2022 day 15 covers in a row.
+use std::cmp::Ordering;
+use std::fmt;
+
+/// The integers `start..=end`. An interval is never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    /// `None` if `start` is after `end`.
+    pub fn new(start: i64, end: i64) -> Option<Interval> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn point(x: i64) -> Interval {
+        Interval { start: x, end: x }
+    }
+
+    /// How many integers the interval holds. There is no `is_empty`, as an interval never is.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(self) -> u128 {
+        self.start.abs_diff(self.end) as u128 + 1
+    }
+
+    pub fn contains(self, x: i64) -> bool {
+        self.start <= x && x <= self.end
+    }
+
+    /// Whether `other` lies entirely within this interval.
+    pub fn includes(self, other: Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(self, other: Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(self, other: Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Whether the two intervals overlap or are adjacent, so their union is one interval.
+    fn touches(self, other: Interval) -> bool {
+        self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// A set of integers, kept as sorted intervals that neither overlap nor touch.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// The intervals of the set, in order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers the set holds.
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(|i| i.len()).sum()
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        self.intervals
+            .binary_search_by(|i| {
+                if i.end < x {
+                    Ordering::Less
+                } else if i.start > x {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        // The intervals before the first one `interval` touches stay, as do those after the
+        // last one; everything in between merges with it.
+        let first = self
+            .intervals
+            .partition_point(|i| i.end.saturating_add(1) < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|i| i.start <= interval.end.saturating_add(1));
+        let mut merged = interval;
+        for i in &self.intervals[first..last] {
+            merged.start = merged.start.min(i.start);
+            merged.end = merged.end.max(i.end);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let (mut a, mut b) = (0, 0);
+        let mut result = vec![];
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (x, y) = (self.intervals[a], other.intervals[b]);
+            result.extend(x.intersection(y));
+            if x.end < y.end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+        IntervalSet { intervals: result }
+    }
+
+    /// The integers of this set that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = vec![];
+        let mut b = 0;
+        for &interval in &self.intervals {
+            let mut rest = Some(interval);
+            while let Some(current) = rest {
+                // Skip the intervals of `other` that end before this one starts.
+                while b < other.intervals.len() && other.intervals[b].end < current.start {
+                    b += 1;
+                }
+                match other.intervals.get(b) {
+                    Some(cut) if cut.start <= current.end => {
+                        // No integer comes before `i64::MIN` or after `i64::MAX`.
+                        let before = cut.start.checked_sub(1);
+                        result.extend(before.and_then(|end| Interval::new(current.start, end)));
+                        let after = cut.end.checked_add(1);
+                        rest = after.and_then(|start| Interval::new(start, current.end));
+                    }
+                    _ => {
+                        result.push(current);
+                        rest = None;
+                    }
+                }
+            }
+        }
+        IntervalSet { intervals: result }
+    }
+
+    /// The integers between the intervals of the set, as intervals.
+    pub fn gaps(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals
+            .windows(2)
+            .filter_map(|w| Interval::new(w[0].end + 1, w[1].start - 1))
+    }
+}
+
+/// Merges the intervals, in any order, into a set.
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = Interval>>(intervals: T) -> Self {
+        let mut sorted: Vec<Interval> = intervals.into_iter().collect();
+        sorted.sort_unstable();
+        let mut merged: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match merged.last_mut() {
+                Some(last) if last.touches(interval) => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        IntervalSet { intervals: merged }
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        IntervalSet {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, interval) in self.intervals.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{interval}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interval::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|&(s, e)| Interval::new(s, e).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn it_works() {
+        let a = Interval::new(2, 8).unwrap();
+        let b = Interval::new(3, 7).unwrap();
+        assert!(a.includes(b) && !b.includes(a));
+        assert!(a.overlaps(Interval::point(8)) && !a.overlaps(Interval::point(9)));
+        assert_eq!(Interval::new(5, 4), None);
+        assert_eq!(a.len(), 7);
+
+        let merged = set(&[(5, 7), (1, 2), (3, 3), (10, 12), (11, 11)]);
+        assert_eq!(merged.to_string(), "1-3,5-7,10-12");
+        assert_eq!(merged.len(), 9);
+        assert!(merged.contains(6) && !merged.contains(4) && !merged.contains(13));
+        assert_eq!(
+            merged.gaps().collect::<Vec<_>>(),
+            set(&[(4, 4), (8, 9)]).intervals
+        );
+
+        let other = set(&[(2, 5), (9, 10)]);
+        assert_eq!(merged.union(&other).to_string(), "1-7,9-12");
+        assert_eq!(merged.intersection(&other).to_string(), "2-3,5-5,10-10");
+        assert_eq!(merged.difference(&other).to_string(), "1-1,6-7,11-12");
+        assert_eq!(other.difference(&merged).to_string(), "4-4,9-9");
+
+        let mut inserted = merged.clone();
+        inserted.insert(Interval::new(4, 8).unwrap());
+        assert_eq!(inserted.to_string(), "1-8,10-12");
+        inserted.insert(Interval::point(9));
+        assert_eq!(inserted, set(&[(1, 12)]));
+    }
+
+    #[test]
+    fn extremes() {
+        let (min, max) = (i64::MIN, i64::MAX);
+        let everything = Interval::new(min, max).unwrap();
+        assert_eq!(everything.len(), 1 << 64);
+        assert_eq!(IntervalSet::from(everything).len(), 1 << 64);
+
+        let low = set(&[(min, 5)]);
+        let high = set(&[(-5, max)]);
+        let without = |set: &IntervalSet, x| set.difference(&Interval::point(x).into());
+        assert_eq!(without(&low, min), set(&[(min + 1, 5)]));
+        assert_eq!(without(&high, max), set(&[(-5, max - 1)]));
+        assert_eq!(without(&set(&[(min, min)]), min), IntervalSet::new());
+        assert_eq!(without(&set(&[(max, max)]), max), IntervalSet::new());
+        assert_eq!(low.difference(&high), set(&[(min, -6)]));
+        assert_eq!(high.difference(&low), set(&[(6, max)]));
+        assert_eq!(low.union(&high).to_string(), format!("{min}-{max}"));
+        assert_eq!(low.union(&high).gaps().count(), 0);
+    }
+}
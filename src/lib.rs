@@ -0,0 +1,19 @@
+pub mod alloc_stats;
+pub mod bench;
+pub mod cache;
+pub mod checkpoint;
+pub mod control;
+pub mod events;
+pub mod input;
+pub mod inspect;
+pub mod interval;
+pub mod parse;
+pub mod runner;
+pub mod scale;
+pub mod simulation;
+pub mod solver;
+pub mod verify;
+pub mod y2022;
+
+#[cfg(feature = "python")]
+mod python;
@@ -1,30 +1,357 @@
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
+use std::collections::HashMap;
+use std::io::{self, IsTerminal, Write};
+use std::sync::Arc;
+use std::time::Duration;
+use std::{env, process};
+
+use aoc_2022::cache::Cache;
+use aoc_2022::checkpoint::Checkpoints;
+use aoc_2022::events::EventLog;
+use aoc_2022::runner::Outcome;
+use aoc_2022::solver::Puzzle;
+use aoc_2022::*;
+
+const USAGE: &str = "usage: aoc-2022 [bench [YEAR [DAY...]] | run (all | YEAR [DAY [PART]]) [OPTIONS] | verify [YEAR [DAY...]] [OPTIONS] | cache clear | inspect YEAR DAY [FILE] | scale YEAR DAY [PART] [OPTIONS]]
+options: --timeout SECS, --resume, --no-cache, --steps N, --trace FILE";
 
 fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None => run_all(),
+        Some("bench") => bench(&args[1..]),
+        Some("run") => run(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("cache") => cache(&args[1..]),
+        Some("inspect") => inspect(&args[1..]),
+        Some("scale") => scale(&args[1..]),
+        Some(cmd) => usage(&format!("unknown command {cmd}")),
+    }
+}
+
+fn usage(error: &str) -> ! {
+    eprintln!("{error}\n{USAGE}");
+    process::exit(2);
+}
+
+/// Flags shared by `run`, `verify` and `scale`.
+struct Options {
+    timeout: Option<Duration>,
+    resume: bool,
+    use_cache: bool,
+    /// How many sizes `scale` measures.
+    steps: Option<u32>,
+    /// Where to write the events of the simulations, as JSON Lines.
+    trace: Option<EventLog>,
+}
+
+/// Splits the arguments into positional ones and [`Options`].
+fn parse_options(args: &[String]) -> (Vec<&str>, Options) {
+    let mut positional = vec![];
+    let mut options = Options {
+        timeout: None,
+        resume: false,
+        use_cache: true,
+        steps: None,
+        trace: None,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--resume" => options.resume = true,
+            "--no-cache" => options.use_cache = false,
+            "--timeout" => {
+                let secs: f64 = args
+                    .next()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or_else(|| usage("--timeout expects a number of seconds"));
+                options.timeout = Some(Duration::from_secs_f64(secs));
+            }
+            "--steps" => {
+                let steps = args
+                    .next()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or_else(|| usage("--steps expects a number"));
+                options.steps = Some(steps);
+            }
+            "--trace" => {
+                let path = args
+                    .next()
+                    .unwrap_or_else(|| usage("--trace expects a file name"));
+                let log = EventLog::create(path.as_ref()).unwrap_or_else(|e| {
+                    eprintln!("could not create {path}: {e}");
+                    process::exit(1);
+                });
+                options.trace = Some(log);
+            }
+            _ => positional.push(arg.as_str()),
+        }
+    }
+    (positional, options)
+}
+
+fn parse_number(arg: &str) -> u8 {
+    arg.parse().unwrap_or_else(|_| usage("expected a number"))
+}
+
+fn parse_year(arg: &str) -> u16 {
+    let year = arg.parse().unwrap_or_else(|_| usage("expected a year"));
+    if solver::year(year).is_none() {
+        usage(&format!("unknown year {year}"));
+    }
+    year
+}
+
+/// All parts of the given days of `year`, of every day if none are given, or of every year if
+/// there is no `year` either.
+fn parts_of(year: Option<u16>, days: &[u8]) -> Vec<(&'static Puzzle, u8)> {
+    if let Some(year) = year {
+        for day in days {
+            if solver::puzzle(year, *day).is_none() {
+                usage(&format!("unknown day {day} of {year}"));
+            }
+        }
+    }
+    solver::puzzles()
+        .filter(|p| year.is_none_or(|y| y == p.year))
+        .filter(|p| days.is_empty() || days.contains(&p.day))
+        .flat_map(|p| (1..=p.parts.len() as u8).map(move |part| (p, part)))
+        .collect()
+}
+
+/// A year followed by days, as taken by `verify` and `bench`; nothing stands for every year.
+fn year_and_days(positional: &[&str]) -> (Option<u16>, Vec<u8>) {
+    match positional {
+        [] => (None, vec![]),
+        [year, days @ ..] => (
+            Some(parse_year(year)),
+            days.iter().map(|d| parse_number(d)).collect(),
+        ),
+    }
+}
+
+struct Solved {
+    outcome: Outcome,
+    elapsed: Duration,
+    cached: bool,
+}
+
+/// Solves one part, answering from the cache if possible and drawing a progress bar while the
+/// solver runs. Parts whose events are traced always run, so the trace is complete.
+fn solve(puzzle: &Puzzle, part: u8, input: &str, options: &Options) -> Solved {
+    let cache = Cache::default();
+    if options.use_cache && options.trace.is_none() {
+        if let Some(answer) = cache.get(puzzle, part, input) {
+            return Solved {
+                outcome: Outcome::Solved(answer),
+                elapsed: Duration::ZERO,
+                cached: true,
+            };
+        }
+    }
+
+    let (year, day) = (puzzle.year, puzzle.day);
+    let show_progress = io::stderr().is_terminal();
+    let checkpoints = Arc::new(Checkpoints::new(year, day, part, input, options.resume));
+    if options.resume && !checkpoints.matches() {
+        eprintln!("{year} day {day} part {part}: no checkpoint to resume, starting over");
+    }
+    let run = runner::run(
+        puzzle.part(part).unwrap(),
+        input.to_string(),
+        options.timeout,
+        Some(checkpoints.clone()),
+        options
+            .trace
+            .as_ref()
+            .map(|log| log.for_part(year, day, part)),
+        |elapsed, progress| {
+            if show_progress {
+                eprint!("\r\x1b[2K{}", runner::progress_bar(elapsed, progress));
+                let _ = io::stderr().flush();
+            }
+        },
+    );
+    if show_progress {
+        eprint!("\r\x1b[2K");
+    }
+    if let Some(Err(e)) = options.trace.as_ref().map(EventLog::finish) {
+        eprintln!("could not write the events of {year} day {day} part {part}: {e}");
+    }
+    match &run.outcome {
+        Outcome::Solved(answer) if options.use_cache => {
+            if let Err(e) = cache.put(puzzle, part, input, answer) {
+                eprintln!("could not cache the answer of {year} day {day} part {part}: {e}");
+            }
+        }
+        Outcome::TimedOut if checkpoints.matches() => eprintln!(
+            "{year} day {day} part {part}: saved progress to {}, continue with --resume",
+            checkpoints.path().display()
+        ),
+        _ => {}
+    }
+    Solved {
+        outcome: run.outcome,
+        elapsed: run.elapsed,
+        cached: false,
+    }
+}
+
+fn run(args: &[String]) {
+    let (positional, options) = parse_options(args);
+    let parts = match positional[..] {
+        ["all"] => parts_of(None, &[]),
+        [year] => parts_of(Some(parse_year(year)), &[]),
+        [year, day] => parts_of(Some(parse_year(year)), &[parse_number(day)]),
+        [year, day, part] => {
+            let year = parse_year(year);
+            let day = parse_number(day);
+            let part = parse_number(part);
+            let puzzle = solver::puzzle(year, day)
+                .unwrap_or_else(|| usage(&format!("unknown day {day} of {year}")));
+            if puzzle.part(part).is_none() {
+                usage(&format!("{year} day {day} has no part {part}"));
+            }
+            vec![(puzzle, part)]
+        }
+        _ => usage("run expects `all` or a year and an optional day and part"),
+    };
+
+    let mut failed = false;
+    let mut inputs = HashMap::new();
+    for (puzzle, part) in parts {
+        let (year, day) = (puzzle.year, puzzle.day);
+        let input = inputs
+            .entry((year, day))
+            .or_insert_with(|| input::load(puzzle.input));
+        let solved = solve(puzzle, part, input, &options);
+        match solved.outcome {
+            Outcome::Solved(answer) if solved.cached => {
+                println!("{year} day {day} part {part}: {answer} (cached)")
+            }
+            Outcome::Solved(answer) => {
+                println!(
+                    "{year} day {day} part {part}: {answer} ({:.2?})",
+                    solved.elapsed
+                )
+            }
+            Outcome::TimedOut => {
+                failed = true;
+                println!(
+                    "{year} day {day} part {part}: timed out after {:.2?}",
+                    solved.elapsed
+                );
+            }
+            Outcome::Failed(message) => {
+                failed = true;
+                println!("{year} day {day} part {part}: failed: {message}")
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn verify(args: &[String]) {
+    let (positional, options) = parse_options(args);
+    let (year, days) = year_and_days(&positional);
+    let answers = verify::load_answers(verify::ANSWERS);
+    let mut checks = vec![];
+    let mut inputs = HashMap::new();
+    for (puzzle, part) in parts_of(year, &days) {
+        let input = inputs
+            .entry((puzzle.year, puzzle.day))
+            .or_insert_with(|| input::load(puzzle.input));
+        let solved = solve(puzzle, part, input, &options);
+        checks.push(verify::Check {
+            year: puzzle.year,
+            day: puzzle.day,
+            part,
+            expected: answers.get(&(puzzle.year, puzzle.day, part)).cloned(),
+            outcome: solved.outcome,
+            elapsed: solved.elapsed,
+            cached: solved.cached,
+        });
+    }
+    print!("{}", verify::report(&checks));
+    if !checks.iter().all(verify::Check::passed) {
+        process::exit(1);
+    }
+}
+
+fn cache(args: &[String]) {
+    match args {
+        [cmd] if cmd == "clear" => {
+            let removed = Cache::default()
+                .clear()
+                .unwrap_or_else(|e| panic!("could not clear {}: {e}", cache::DIR));
+            println!("removed {removed} cached answers");
+        }
+        _ => usage("cache expects `clear`"),
+    }
+}
+
+fn inspect(args: &[String]) {
+    let (year, day, path) = match args {
+        [year, day] => (parse_year(year), parse_number(day), None),
+        [year, day, path] => (parse_year(year), parse_number(day), Some(path.as_str())),
+        _ => usage("inspect expects a year, a day and an optional input file"),
+    };
+    let puzzle =
+        solver::puzzle(year, day).unwrap_or_else(|| usage(&format!("unknown day {day} of {year}")));
+    let inspect = inspect::inspector(year, day)
+        .unwrap_or_else(|| usage(&format!("{year} day {day} has nothing to inspect")));
+    print!("{}", inspect(&input::load(path.unwrap_or(puzzle.input))));
+}
+
+fn scale(args: &[String]) {
+    let (positional, options) = parse_options(args);
+    let (year, day, parts) = match positional[..] {
+        [year, day] => (parse_year(year), parse_number(day), None),
+        [year, day, part] => (
+            parse_year(year),
+            parse_number(day),
+            Some(parse_number(part)),
+        ),
+        _ => usage("scale expects a year, a day and an optional part"),
+    };
+    let generator = scale::generator(year, day)
+        .unwrap_or_else(|| usage(&format!("there is no input generator for {year} day {day}")));
+    let parts: Vec<(&Puzzle, u8)> = parts_of(Some(year), &[day])
+        .into_iter()
+        .filter(|(_, part)| parts.is_none_or(|p| p == *part))
+        .collect();
+    if parts.is_empty() {
+        usage(&format!("{year} day {day} has no such part"));
+    }
+    let steps = options.steps.unwrap_or(6);
+    let timeout = options.timeout.unwrap_or(Duration::from_secs(10));
+    let scalings: Vec<_> = parts
+        .into_iter()
+        .map(|(puzzle, part)| scale::measure(puzzle, part, generator, steps, timeout))
+        .collect();
+    print!("{}", scale::report(&scalings));
+    if scalings.iter().any(scale::Scaling::too_fast) {
+        process::exit(1);
+    }
+}
+
+fn bench(args: &[String]) {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let (year, days) = year_and_days(&args);
+    let mut measurements = vec![];
+    for puzzle in solver::puzzles() {
+        if year.is_none_or(|y| y == puzzle.year) && (days.is_empty() || days.contains(&puzzle.day))
+        {
+            measurements.extend(bench::measure(puzzle));
+        }
+    }
+    print!("{}", bench::report(&measurements));
+}
+
+fn run_all() {
+    use aoc_2022::y2022::*;
+
     day01::solve1();
     day01::solve2();
     day01::solve1_imp();
@@ -35,6 +362,7 @@ fn main() {
     day03::solve2();
     day04::solve1();
     day04::solve2();
+    day04::solve3();
     day05::solve1();
     day05::solve2();
     day06::solve1();
@@ -0,0 +1,125 @@
+//! Errors and small helpers shared by the input parsers.
+//!
+//! Parsers return a [`ParseError`] for anything they cannot make sense of instead of panicking,
+//! so malformed or generated inputs can be reported. [`check`] runs the parser of a day, which
+//! is what the fuzz targets in `fuzz/` call.
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The 1-based line of the input the error is about, if it is about a single line.
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    /// Attaches a line number unless the error already has one.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {line}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+pub fn number<T: FromStr>(s: &str) -> ParseResult<T> {
+    s.parse()
+        .map_err(|_| ParseError::new(format!("expected a number, found {s:?}")))
+}
+
+pub fn split_once<'a>(s: &'a str, separator: &str) -> ParseResult<(&'a str, &'a str)> {
+    s.split_once(separator)
+        .ok_or_else(|| ParseError::new(format!("expected {separator:?} in {s:?}")))
+}
+
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> ParseResult<&'a str> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(format!("expected {s:?} to start with {prefix:?}")))
+}
+
+/// Parses every non-blank line with `parse`, attaching the line number to its errors.
+pub fn lines<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> ParseResult<T>,
+) -> ParseResult<Vec<T>> {
+    numbered_lines(input, |_, line| parse(line))
+}
+
+/// Like [`lines`], also passing `parse` the 1-based number of the line, for results that have
+/// to name their line later.
+pub fn numbered_lines<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(usize, &'a str) -> ParseResult<T>,
+) -> ParseResult<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse(index + 1, line).map_err(|e| e.on_line(index + 1)))
+        .collect()
+}
+
+/// Runs the parser of `day` of `year` on `input`, discarding what it parsed.
+pub fn check(year: u16, day: u8, input: &str) -> ParseResult<()> {
+    match year {
+        y2022::YEAR => y2022::check(day, input),
+        _ => Err(ParseError::new(format!("there is no year {year}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::*;
+
+    #[test]
+    fn it_works() {
+        let error = lines("1\n\nx\n", number::<u32>).unwrap_err();
+        assert_eq!(error.to_string(), "line 3: expected a number, found \"x\"");
+
+        // None of the parsers may panic, whatever they are given.
+        let garbage = [
+            "",
+            "\n",
+            "x",
+            "1 2 3\n\n4",
+            "[[[",
+            "Valve : ->",
+            "#.#\n#",
+            "move 0 from 0 to 9\n",
+        ];
+        for puzzle in solver::puzzles() {
+            for input in garbage {
+                let _ = check(puzzle.year, puzzle.day, input);
+            }
+        }
+        for input in garbage {
+            let _ = y2022::day13::parse_tree(input);
+        }
+
+        for puzzle in solver::puzzles() {
+            check(puzzle.year, puzzle.day, &input::load(puzzle.input)).unwrap();
+        }
+    }
+}
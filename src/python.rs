@@ -0,0 +1,133 @@
+//! Python bindings, enabled with the `python` feature.
+//!
+//! Build a wheel with `maturin build --release` (or install it into the current virtualenv with
+//! `maturin develop`) and use it as
+//!
+//! ```python
+//! import aoc_2022
+//! aoc_2022.solve(2022, 1, 2, open("inputs/2022/day01.txt").read())
+//! ```
+// pyo3's `#[pyfunction]` expansion trips this lint on every `PyResult` return type.
+#![allow(clippy::useless_conversion)]
+
+use std::cmp::Ordering;
+
+use pyo3::basic::CompareOp;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::solver::{self, Answer};
+use crate::y2022::{day13, day25};
+
+/// Solves the given part of a day of a year on `input` and returns either an `int` or a `str`.
+#[pyfunction]
+fn solve(py: Python<'_>, year: u16, day: u8, part: u8, input: &str) -> PyResult<PyObject> {
+    let answer = solver::solve(year, day, part, input).ok_or_else(|| {
+        PyValueError::new_err(format!("no solver for {year} day {day} part {part}"))
+    })?;
+    Ok(match answer {
+        Answer::Number(n) => n.into_py(py),
+        Answer::Text(s) => s.into_py(py),
+    })
+}
+
+/// Lists all registered `(year, day, part)` triples.
+#[pyfunction]
+fn solvers() -> Vec<(u16, u8, u8)> {
+    solver::puzzles()
+        .flat_map(|p| (1..=p.parts.len() as u8).map(move |part| (p.year, p.day, part)))
+        .collect()
+}
+
+/// Path of the puzzle input of a day, relative to the repository root.
+#[pyfunction]
+fn input_path(year: u16, day: u8) -> PyResult<&'static str> {
+    solver::puzzle(year, day)
+        .map(|p| p.input)
+        .ok_or_else(|| PyValueError::new_err(format!("unknown day {day} of {year}")))
+}
+
+#[pyclass(name = "Snafu", frozen, eq)]
+#[derive(Clone, PartialEq)]
+struct PySnafu(day25::Snafu);
+
+#[pymethods]
+impl PySnafu {
+    #[new]
+    fn new(value: i64) -> Self {
+        PySnafu(day25::Snafu(value))
+    }
+
+    #[staticmethod]
+    fn parse(snafu: &str) -> PyResult<Self> {
+        day25::Snafu::parse(snafu)
+            .map(PySnafu)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    #[getter]
+    fn value(&self) -> i64 {
+        self.0 .0
+    }
+
+    fn __int__(&self) -> i64 {
+        self.0 .0
+    }
+
+    fn __str__(&self) -> String {
+        self.0.print()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Snafu({:?})", self.0.print())
+    }
+
+    fn __add__(&self, other: &Self) -> Self {
+        PySnafu(day25::Snafu(self.0 .0 + other.0 .0))
+    }
+}
+
+#[pyclass(name = "Tree", frozen)]
+#[derive(Clone)]
+struct PyTree(day13::Tree);
+
+#[pymethods]
+impl PyTree {
+    #[staticmethod]
+    fn parse(packet: &str) -> PyResult<Self> {
+        day13::parse_tree(packet)
+            .map(PyTree)
+            .map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp) -> bool {
+        op.matches(day13::cmp_tree(&self.0, &other.0))
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Tree({:?})", self.0.to_string())
+    }
+
+    /// Compares two packets like `cmp` would, returning -1, 0 or 1.
+    fn compare(&self, other: &Self) -> i8 {
+        match day13::cmp_tree(&self.0, &other.0) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }
+    }
+}
+
+#[pymodule]
+fn aoc_2022(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(solvers, m)?)?;
+    m.add_function(wrap_pyfunction!(input_path, m)?)?;
+    m.add_class::<PySnafu>()?;
+    m.add_class::<PyTree>()?;
+    Ok(())
+}
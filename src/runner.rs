@@ -0,0 +1,169 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::checkpoint::Checkpoints;
+use crate::control::{CancelToken, Control, Progress};
+use crate::events::EventLog;
+use crate::solver::{Answer, Part};
+
+/// How long a cancelled solver gets to notice its token before we give up waiting for it.
+const GRACE: Duration = Duration::from_secs(1);
+const TICK: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Answer),
+    TimedOut,
+    Failed(String),
+}
+
+pub struct Run {
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+/// Runs `part` on a worker thread, cancelling it once `timeout` has passed.
+///
+/// `on_tick` is called from the calling thread roughly every 100ms with the time elapsed so far
+/// and the latest progress the solver reported, e.g. to draw a progress bar.
+///
+/// Solvers that support it save their state to `checkpoints` while running, the file is removed
+/// once the part is solved. Those that log events write them to `events`.
+pub fn run(
+    part: Part,
+    input: String,
+    timeout: Option<Duration>,
+    checkpoints: Option<Arc<Checkpoints>>,
+    events: Option<EventLog>,
+    mut on_tick: impl FnMut(Duration, Option<Progress>),
+) -> Run {
+    let cancel = CancelToken::new();
+    let latest: Arc<Mutex<Option<Progress>>> = Arc::new(Mutex::new(None));
+    let mut control = {
+        let latest = latest.clone();
+        Control::new(cancel.clone(), move |p| *latest.lock().unwrap() = Some(p))
+    };
+    if let Some(checkpoints) = &checkpoints {
+        control = control.with_checkpoints(checkpoints.clone());
+    }
+    if let Some(events) = events {
+        control = control.with_events(events);
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let start = Instant::now();
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| part(&input, &control)));
+        let _ = tx.send(result);
+    });
+
+    let result = loop {
+        match rx.recv_timeout(TICK) {
+            Ok(result) => break Some(result),
+            Err(mpsc::RecvTimeoutError::Disconnected) => break None,
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+        }
+        let elapsed = start.elapsed();
+        if timeout.is_some_and(|t| elapsed >= t) {
+            cancel.cancel();
+            let _ = rx.recv_timeout(GRACE);
+            return Run {
+                outcome: Outcome::TimedOut,
+                elapsed,
+            };
+        }
+        on_tick(elapsed, *latest.lock().unwrap());
+    };
+
+    let outcome = match result {
+        Some(Ok(Ok(answer))) => Outcome::Solved(answer),
+        Some(Ok(Err(_))) => Outcome::TimedOut,
+        Some(Err(panic)) => Outcome::Failed(panic_message(panic.as_ref())),
+        None => Outcome::Failed("solver thread disappeared".to_string()),
+    };
+    if let (Outcome::Solved(_), Some(checkpoints)) = (&outcome, checkpoints) {
+        checkpoints.clear();
+    }
+    Run {
+        outcome,
+        elapsed: start.elapsed(),
+    }
+}
+
+fn panic_message(panic: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = panic.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = panic.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "solver panicked".to_string()
+    }
+}
+
+/// Renders a one-line progress bar like `[#####-----] 5/10 rounds 1.20s`.
+pub fn progress_bar(elapsed: Duration, progress: Option<Progress>) -> String {
+    let width = 30;
+    match progress {
+        Some(Progress {
+            stage,
+            done,
+            total: Some(total),
+        }) if total > 0 => {
+            let filled = (done.min(total) * width / total) as usize;
+            format!(
+                "[{}{}] {done}/{total} {stage} {elapsed:.2?}",
+                "#".repeat(filled),
+                "-".repeat(width as usize - filled)
+            )
+        }
+        Some(Progress { stage, done, .. }) => format!("{stage}: {done} {elapsed:.2?}"),
+        None => format!("running {elapsed:.2?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::*;
+
+    #[test]
+    fn it_works() {
+        let slow: Part = |_, control| loop {
+            control.check()?;
+            control.report("spins", 1, Some(2));
+            thread::sleep(Duration::from_millis(5));
+        };
+        let mut ticks = vec![];
+        let timed_out = run(
+            slow,
+            String::new(),
+            Some(Duration::from_millis(300)),
+            None,
+            None,
+            |_, p| ticks.push(p),
+        );
+        assert_eq!(timed_out.outcome, Outcome::TimedOut);
+        assert!(ticks.iter().any(|p| p.is_some_and(|p| p.stage == "spins")));
+
+        let fast: Part = |input, _| Ok(Answer::Text(input.to_uppercase()));
+        let solved = run(fast, "abc".to_string(), None, None, None, |_, _| {});
+        assert_eq!(
+            solved.outcome,
+            Outcome::Solved(Answer::Text("ABC".to_string()))
+        );
+
+        let progress = Progress {
+            stage: "rounds",
+            done: 5,
+            total: Some(10),
+        };
+        assert!(
+            progress_bar(Duration::ZERO, Some(progress)).starts_with(&format!(
+                "[{}{}] 5/10 rounds",
+                "#".repeat(15),
+                "-".repeat(15)
+            ))
+        );
+    }
+}
@@ -0,0 +1,383 @@
+//! Running solvers on generated inputs of growing size to see how their running time grows.
+//!
+//! Each size is twice the one before, and the growth exponent `k` of `time ~ size^k` is fitted
+//! to the measurements by least squares on their logarithms.
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::runner::{self, Outcome};
+use crate::solver::Puzzle;
+
+/// How far the fitted exponent may exceed the expected one before [`report`] warns.
+pub const TOLERANCE: f64 = 0.25;
+
+/// Measurements faster than this are mostly noise and left out of the fit.
+const MIN_FIT_TIME: Duration = Duration::from_millis(1);
+
+/// A xorshift generator, so generated inputs are the same on every run.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // Xorshift never leaves zero.
+        Rng(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in `0..n`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+}
+
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    /// What the size of a generated input counts.
+    pub unit: &'static str,
+    /// The smallest size measured.
+    pub base: usize,
+    /// The exponent the running time should grow with at most.
+    pub expected: f64,
+    pub generate: fn(usize, &mut Rng) -> String,
+}
+
+pub static GENERATORS: &[Generator] = &[
+    Generator {
+        year: 2022,
+        day: 1,
+        unit: "elves",
+        base: 10_000,
+        expected: 1.0,
+        generate: elves,
+    },
+    Generator {
+        year: 2022,
+        day: 4,
+        unit: "pairs",
+        base: 10_000,
+        expected: 1.0,
+        generate: section_pairs,
+    },
+    Generator {
+        year: 2022,
+        day: 6,
+        unit: "characters",
+        base: 50_000,
+        expected: 1.0,
+        generate: datastream,
+    },
+    Generator {
+        year: 2022,
+        day: 7,
+        unit: "directories",
+        base: 50,
+        expected: 1.0,
+        generate: terminal_output,
+    },
+    Generator {
+        year: 2022,
+        day: 9,
+        unit: "moves",
+        base: 2_000,
+        expected: 1.0,
+        generate: rope_moves,
+    },
+    Generator {
+        year: 2022,
+        day: 18,
+        unit: "cubes",
+        base: 1_000,
+        expected: 1.0,
+        generate: droplet,
+    },
+    // Every number moves past up to all others, so mixing is quadratic at best.
+    Generator {
+        year: 2022,
+        day: 20,
+        unit: "numbers",
+        base: 500,
+        expected: 2.0,
+        generate: encrypted_file,
+    },
+    Generator {
+        year: 2022,
+        day: 25,
+        unit: "numbers",
+        base: 10_000,
+        expected: 1.0,
+        generate: snafu_numbers,
+    },
+];
+
+pub fn generator(year: u16, day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.year == year && g.day == day)
+}
+
+fn elves(size: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        for _ in 0..1 + rng.below(5) {
+            writeln!(out, "{}", 1 + rng.below(10_000)).unwrap();
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn section_pairs(size: usize, rng: &mut Rng) -> String {
+    let range = |rng: &mut Rng| {
+        let start = 1 + rng.below(99);
+        (start, start + rng.below(100 - start))
+    };
+    let mut out = String::new();
+    for _ in 0..size {
+        let (a, b) = range(rng);
+        let (c, d) = range(rng);
+        writeln!(out, "{a}-{b},{c}-{d}").unwrap();
+    }
+    out
+}
+
+/// Three letters repeated, so no marker turns up before the one at the very end.
+fn datastream(size: usize, rng: &mut Rng) -> String {
+    let mut out: String = (0..size)
+        .map(|_| (b'a' + rng.below(3) as u8) as char)
+        .collect();
+    out.push_str("defghijklmnopq\n");
+    out
+}
+
+/// A deep directory tree: every directory is a child of one of the three made before it.
+fn terminal_output(size: usize, rng: &mut Rng) -> String {
+    let mut children = vec![vec![]; size];
+    for dir in 1..size {
+        let parent = dir - 1 - rng.below(dir.min(3) as u64) as usize;
+        children[parent].push(dir);
+    }
+    let mut out = String::from("$ cd /\n");
+    // Walks the tree depth first, `None` marking the way back up.
+    let mut stack = vec![Some(0)];
+    while let Some(entry) = stack.pop() {
+        let Some(dir) = entry else {
+            out.push_str("$ cd ..\n");
+            continue;
+        };
+        if dir != 0 {
+            writeln!(out, "$ cd d{dir}").unwrap();
+            stack.push(None);
+        }
+        out.push_str("$ ls\n");
+        for _ in 0..rng.below(3) {
+            writeln!(out, "{} f", 1 + rng.below(1_000)).unwrap();
+        }
+        for child in &children[dir] {
+            writeln!(out, "dir d{child}").unwrap();
+            stack.push(Some(*child));
+        }
+    }
+    out
+}
+
+fn rope_moves(size: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let direction = ["U", "D", "L", "R"][rng.below(4) as usize];
+        writeln!(out, "{direction} {}", 1 + rng.below(10)).unwrap();
+    }
+    out
+}
+
+/// Cubes scattered through a box about twice as large as their number.
+fn droplet(size: usize, rng: &mut Rng) -> String {
+    let side = ((2 * size) as f64).cbrt().ceil() as u64 + 1;
+    let mut out = String::new();
+    for _ in 0..size {
+        let (x, y, z) = (rng.below(side), rng.below(side), rng.below(side));
+        writeln!(out, "{x},{y},{z}").unwrap();
+    }
+    out
+}
+
+/// Numbers of up to four digits, one of them the zero the answer is counted from.
+fn encrypted_file(size: usize, rng: &mut Rng) -> String {
+    let zero = rng.below(size as u64);
+    let mut out = String::new();
+    for i in 0..size as u64 {
+        let number = if i == zero {
+            0
+        } else {
+            rng.below(20_000) as i64 - 10_000
+        };
+        writeln!(out, "{number}").unwrap();
+    }
+    out
+}
+
+/// Numbers of up to six digits, so their sum stays far from overflowing.
+fn snafu_numbers(size: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        out.push(['1', '2'][rng.below(2) as usize]);
+        for _ in 0..rng.below(6) {
+            out.push(['=', '-', '0', '1', '2'][rng.below(5) as usize]);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+pub struct Sample {
+    pub size: usize,
+    pub elapsed: Duration,
+}
+
+pub struct Scaling {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub unit: &'static str,
+    pub expected: f64,
+    pub samples: Vec<Sample>,
+    /// Why the sizes stopped growing early, if they did.
+    pub stopped: Option<String>,
+}
+
+impl Scaling {
+    pub fn exponent(&self) -> Option<f64> {
+        fit_exponent(&self.samples)
+    }
+
+    /// Whether the running time grows noticeably faster than expected.
+    pub fn too_fast(&self) -> bool {
+        self.exponent()
+            .is_some_and(|k| k > self.expected + TOLERANCE)
+    }
+}
+
+/// The slope of the least-squares line through `(ln size, ln time)`, or `None` with fewer than
+/// three samples slow enough to measure.
+pub fn fit_exponent(samples: &[Sample]) -> Option<f64> {
+    let points: Vec<(f64, f64)> = samples
+        .iter()
+        .filter(|s| s.elapsed >= MIN_FIT_TIME)
+        .map(|s| ((s.size as f64).ln(), s.elapsed.as_secs_f64().ln()))
+        .collect();
+    if points.len() < 3 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+    let covariance: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+    let variance: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+    Some(covariance / variance)
+}
+
+/// Runs a part on `steps` generated inputs, doubling the size each time. Stops early once a run
+/// takes longer than `timeout` or fails.
+pub fn measure(
+    puzzle: &Puzzle,
+    part: u8,
+    generator: &Generator,
+    steps: u32,
+    timeout: Duration,
+) -> Scaling {
+    let mut scaling = Scaling {
+        year: puzzle.year,
+        day: puzzle.day,
+        part,
+        unit: generator.unit,
+        expected: generator.expected,
+        samples: vec![],
+        stopped: None,
+    };
+    let solver = puzzle.part(part).expect("the part should exist");
+    for step in 0..steps {
+        let size = generator.base << step;
+        let input = (generator.generate)(size, &mut Rng::new(size as u64));
+        let run = runner::run(solver, input, Some(timeout), None, None, |_, _| {});
+        match run.outcome {
+            Outcome::Solved(_) => scaling.samples.push(Sample {
+                size,
+                elapsed: run.elapsed,
+            }),
+            Outcome::TimedOut => {
+                scaling.stopped = Some(format!("size {size} took longer than {timeout:.2?}"));
+                break;
+            }
+            Outcome::Failed(message) => {
+                scaling.stopped = Some(format!("size {size} failed: {message}"));
+                break;
+            }
+        }
+    }
+    scaling
+}
+
+pub fn report(scalings: &[Scaling]) -> String {
+    let mut out = String::new();
+    for scaling in scalings {
+        writeln!(
+            out,
+            "{} day {} part {}",
+            scaling.year, scaling.day, scaling.part
+        )
+        .unwrap();
+        writeln!(out, "{:>12} {:>12}", scaling.unit, "time").unwrap();
+        for sample in &scaling.samples {
+            let time = format!("{:.2?}", sample.elapsed);
+            writeln!(out, "{:>12} {:>12}", sample.size, time).unwrap();
+        }
+        if let Some(reason) = &scaling.stopped {
+            writeln!(out, "stopped: {reason}").unwrap();
+        }
+        match scaling.exponent() {
+            Some(k) if scaling.too_fast() => writeln!(
+                out,
+                "warning: grows like n^{k:.2}, expected at most n^{:.2}",
+                scaling.expected
+            )
+            .unwrap(),
+            Some(k) => writeln!(
+                out,
+                "grows like n^{k:.2}, expected at most n^{:.2}",
+                scaling.expected
+            )
+            .unwrap(),
+            None => writeln!(out, "too few measurable runs to fit a growth exponent").unwrap(),
+        }
+        writeln!(out).unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse;
+    use crate::scale::*;
+
+    #[test]
+    fn it_works() {
+        let quadratic: Vec<Sample> = (1..=5)
+            .map(|i| Sample {
+                size: 100 << i,
+                elapsed: Duration::from_millis(1 << (2 * i)),
+            })
+            .collect();
+        let k = fit_exponent(&quadratic).unwrap();
+        assert!((k - 2.0).abs() < 1e-9, "{k}");
+        assert_eq!(fit_exponent(&quadratic[..2]), None);
+
+        for generator in GENERATORS {
+            let input = (generator.generate)(50, &mut Rng::new(1));
+            parse::check(generator.year, generator.day, &input).unwrap();
+        }
+    }
+}
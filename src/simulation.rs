@@ -0,0 +1,202 @@
+//! Puzzles that advance a state one step at a time: rope moves, CPU cycles, monkey rounds,
+//! sand grains, falling rocks, elf rounds and blizzards.
+//!
+//! A day implements [`Simulation`] for its state, and running, cycle detection, tracing and
+//! rendering are done by the functions here.
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
+use std::hash::Hash;
+
+use crate::control::{Cancelled, Control};
+use crate::events::Event;
+
+pub trait Simulation {
+    /// What identifies a state for cycle detection and tracing. Two states with equal
+    /// snapshots must behave the same from then on.
+    type Snapshot: Clone + Eq + Hash;
+
+    /// Advances the state by one step.
+    fn step(&mut self);
+
+    /// Whether the simulation came to an end; simulations that run forever never are.
+    fn is_done(&self) -> bool {
+        false
+    }
+
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// What the last step did, for the [event log](crate::events).
+    fn events(&self) -> Vec<Event> {
+        vec![]
+    }
+}
+
+/// Steps until the simulation is done, returning the number of steps taken.
+pub fn run<S: Simulation>(sim: &mut S) -> usize {
+    let mut steps = 0;
+    while !sim.is_done() {
+        sim.step();
+        steps += 1;
+    }
+    steps
+}
+
+/// Takes up to `steps` steps, fewer if the simulation is done before. Returns the number taken.
+pub fn run_for<S: Simulation>(sim: &mut S, steps: usize) -> usize {
+    let mut taken = 0;
+    while taken < steps && !sim.is_done() {
+        sim.step();
+        taken += 1;
+    }
+    taken
+}
+
+/// Like [`run_for`] (or [`run`] without a limit), but checks the control before every step,
+/// reports the steps taken as `stage` and writes the events of every step to its event log.
+pub fn run_controlled<S: Simulation>(
+    sim: &mut S,
+    steps: Option<usize>,
+    control: &Control,
+    stage: &'static str,
+) -> Result<usize, Cancelled> {
+    let mut taken = 0;
+    while steps.is_none_or(|s| taken < s) && !sim.is_done() {
+        control.check()?;
+        control.report(stage, taken as u64, steps.map(|s| s as u64));
+        sim.step();
+        taken += 1;
+        if let Some(log) = control.events() {
+            for event in sim.events() {
+                log.write(&event);
+            }
+        }
+    }
+    Ok(taken)
+}
+
+/// A repetition found by [`find_cycle`]: the state after `start + length` steps equals the one
+/// after `start` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Maps a step count to the earliest step with the same state.
+    pub fn reduce(&self, steps: usize) -> usize {
+        if steps < self.start {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.length
+        }
+    }
+}
+
+/// Steps until a snapshot repeats, giving up after `limit` steps or when the simulation is done.
+/// The simulation is left at the step where the repetition was noticed.
+pub fn find_cycle<S: Simulation>(sim: &mut S, limit: usize) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+    for step in 0..=limit {
+        if let Some(start) = seen.insert(sim.snapshot(), step) {
+            return Some(Cycle {
+                start,
+                length: step - start,
+            });
+        }
+        if step == limit || sim.is_done() {
+            break;
+        }
+        sim.step();
+    }
+    None
+}
+
+/// The snapshots of the current state and of the states after each of up to `steps` steps.
+pub fn trace<S: Simulation>(sim: &mut S, steps: usize) -> Vec<S::Snapshot> {
+    let mut snapshots = vec![sim.snapshot()];
+    for _ in 0..steps {
+        if sim.is_done() {
+            break;
+        }
+        sim.step();
+        snapshots.push(sim.snapshot());
+    }
+    snapshots
+}
+
+/// Draws the current state and the states after each of up to `steps` steps, separated by blank
+/// lines.
+pub fn render<S: Simulation + Display>(sim: &mut S, steps: usize) -> String {
+    let mut out = String::new();
+    write!(out, "{sim}").unwrap();
+    for _ in 0..steps {
+        if sim.is_done() {
+            break;
+        }
+        sim.step();
+        write!(out, "\n\n{sim}").unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use crate::simulation::*;
+
+    /// Counts up from `value`, wrapping from 9 back to 3.
+    struct Counter {
+        value: u32,
+        steps: u32,
+    }
+
+    impl Simulation for Counter {
+        type Snapshot = u32;
+
+        fn step(&mut self) {
+            self.value = if self.value == 9 { 3 } else { self.value + 1 };
+            self.steps += 1;
+        }
+
+        fn is_done(&self) -> bool {
+            self.steps == 20
+        }
+
+        fn snapshot(&self) -> u32 {
+            self.value
+        }
+    }
+
+    impl fmt::Display for Counter {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.value)
+        }
+    }
+
+    #[test]
+    fn it_works() {
+        let counter = || Counter { value: 0, steps: 0 };
+        assert_eq!(
+            find_cycle(&mut counter(), 100),
+            Some(Cycle {
+                start: 3,
+                length: 7
+            })
+        );
+        assert_eq!(find_cycle(&mut counter(), 5), None);
+        assert_eq!(
+            Cycle {
+                start: 3,
+                length: 7
+            }
+            .reduce(1_000),
+            3 + 997 % 7
+        );
+        assert_eq!(trace(&mut counter(), 3), vec![0, 1, 2, 3]);
+        assert_eq!(render(&mut counter(), 2), "0\n\n1\n\n2");
+        assert_eq!(run(&mut counter()), 20);
+        assert_eq!(run_for(&mut counter(), 25), 20);
+    }
+}
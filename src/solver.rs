@@ -0,0 +1,167 @@
+use std::fmt;
+
+use crate::control::{Cancelled, Control};
+use crate::*;
+
+/// The result of solving one part of a puzzle. Most parts produce a number, a few (like the
+/// CRT picture of day 10 or the SNAFU number of day 25) produce text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl Answer {
+    /// Writes the answer on a single line, with line breaks of text answers escaped as `\n`.
+    pub fn to_line(&self) -> String {
+        match self {
+            Answer::Number(n) => n.to_string(),
+            Answer::Text(s) => s.replace('\\', "\\\\").replace('\n', "\\n"),
+        }
+    }
+
+    /// Reads an answer written by [`Answer::to_line`]. Anything that parses as a number is one.
+    pub fn from_line(line: &str) -> Answer {
+        if let Ok(n) = line.parse() {
+            return Answer::Number(n);
+        }
+        let mut text = String::with_capacity(line.len());
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+            match (c, chars.clone().next()) {
+                ('\\', Some('n')) => {
+                    text.push('\n');
+                    chars.next();
+                }
+                ('\\', Some('\\')) => {
+                    text.push('\\');
+                    chars.next();
+                }
+                _ => text.push(c),
+            }
+        }
+        Answer::Text(text)
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Number(n as i64)
+            }
+        })*
+    };
+}
+
+answer_from_number!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+/// A solver for one part. The input is [normalised](input::normalise) before the day's parser
+/// sees it. Solvers of the long-running days check the [`Control`] for cancellation and report
+/// progress through it, all others ignore it.
+pub type Part = fn(&str, &Control) -> Result<Answer, Cancelled>;
+
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub input: &'static str,
+    pub parts: &'static [Part],
+    /// Bumped whenever a change to the solver may change its answers, so cached answers of the
+    /// old version are not used.
+    pub version: u32,
+}
+
+impl Puzzle {
+    pub fn part(&self, part: u8) -> Option<Part> {
+        let index = (part as usize).checked_sub(1)?;
+        self.parts.get(index).copied()
+    }
+}
+
+/// Registers the parts of a day, given the module they live in. Parts marked `controlled` take
+/// the [`Control`] as well.
+macro_rules! day {
+    (@version) => { 1 };
+    (@version $version:literal) => { $version };
+    (
+        $year:expr,
+        $day:literal,
+        $module:ident,
+        [$($part:ident),*]
+        $(, version $version:literal)?
+    ) => {
+        $crate::solver::Puzzle {
+            year: $year,
+            day: $day,
+            input: $module::INPUT,
+            parts: &[$(|raw, _| {
+                Ok($module::$part(&$crate::input::normalise(raw)).into())
+            }),*],
+            version: day!(@version $($version)?),
+        }
+    };
+    (
+        $year:expr,
+        $day:literal,
+        $module:ident,
+        controlled [$($part:ident),*]
+        $(, version $version:literal)?
+    ) => {
+        $crate::solver::Puzzle {
+            year: $year,
+            day: $day,
+            input: $module::INPUT,
+            parts: &[$(|raw, control| {
+                $module::$part(&$crate::input::normalise(raw), control).map(Into::into)
+            }),*],
+            version: day!(@version $($version)?),
+        }
+    };
+}
+
+pub(crate) use day;
+
+/// The puzzles of one event, each year living in its own module like [`y2022`].
+pub struct Year {
+    pub year: u16,
+    pub puzzles: &'static [Puzzle],
+}
+
+pub static YEARS: &[Year] = &[Year {
+    year: y2022::YEAR,
+    puzzles: y2022::PUZZLES,
+}];
+
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// The puzzles of every year, oldest first.
+pub fn puzzles() -> impl Iterator<Item = &'static Puzzle> {
+    YEARS.iter().flat_map(|y| y.puzzles)
+}
+
+pub fn puzzle(year: u16, day: u8) -> Option<&'static Puzzle> {
+    self::year(year)?.puzzles.iter().find(|p| p.day == day)
+}
+
+/// Solves a part without a way to cancel it.
+pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Option<Answer> {
+    let part = puzzle(year, day).and_then(|p| p.part(part))?;
+    Some(part(input, &Control::default()).expect("solver without cancel token was cancelled"))
+}
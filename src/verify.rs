@@ -0,0 +1,133 @@
+//! Checking answers against the known-good ones in the answers file.
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::time::Duration;
+
+use crate::runner::Outcome;
+use crate::solver::Answer;
+
+pub const ANSWERS: &str = "inputs/answers.txt";
+
+/// Reads the answers file: one `YEAR DAY PART ANSWER` line per known answer, where the answer is
+/// written as by [`Answer::to_line`]. Empty lines and lines starting with `#` are ignored.
+pub fn load_answers(path: &str) -> HashMap<(u16, u8, u8), Answer> {
+    let text = fs::read_to_string(path).expect("Should have been able to read the answers file");
+    parse_answers(&text)
+}
+
+fn parse_answers(text: &str) -> HashMap<(u16, u8, u8), Answer> {
+    let mut answers = HashMap::new();
+    for line in text.lines() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.splitn(4, ' ');
+        let (Some(year), Some(day), Some(part), Some(answer)) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            panic!("malformed answers line {line}")
+        };
+        let year = year.parse().expect("year should be a number");
+        let day = day.parse().expect("day should be a number");
+        let part = part.parse().expect("part should be a number");
+        answers.insert((year, day, part), Answer::from_line(answer));
+    }
+    answers
+}
+
+pub struct Check {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub expected: Option<Answer>,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+    /// Whether the answer came from the [cache](crate::cache) instead of running the solver.
+    pub cached: bool,
+}
+
+impl Check {
+    pub fn status(&self) -> &'static str {
+        match (&self.outcome, &self.expected) {
+            (Outcome::Solved(_), None) => "unknown",
+            (Outcome::Solved(answer), Some(expected)) if answer == expected => "ok",
+            (Outcome::Solved(_), Some(_)) => "WRONG",
+            (Outcome::TimedOut, _) => "timed out",
+            (Outcome::Failed(_), _) => "failed",
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        matches!(self.status(), "ok" | "unknown")
+    }
+}
+
+pub fn report(checks: &[Check]) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "{:>4} {:>3} {:>4} {:<9} {:>12} {:<6}",
+        "year", "day", "part", "status", "time", "source"
+    )
+    .unwrap();
+    for check in checks {
+        let time = if check.cached {
+            "-".to_string()
+        } else {
+            format!("{:.2?}", check.elapsed)
+        };
+        let source = if check.cached { "cached" } else { "solved" };
+        write!(
+            out,
+            "{:>4} {:>3} {:>4} {:<9} {:>12} {:<6}",
+            check.year,
+            check.day,
+            check.part,
+            check.status(),
+            time,
+            source
+        )
+        .unwrap();
+        match (&check.outcome, &check.expected) {
+            (Outcome::Solved(answer), Some(expected)) if answer != expected => write!(
+                out,
+                "  got {}, expected {}",
+                answer.to_line(),
+                expected.to_line()
+            )
+            .unwrap(),
+            (Outcome::Failed(message), _) => write!(out, "  {message}").unwrap(),
+            _ => {}
+        }
+        writeln!(out).unwrap();
+    }
+    let passed = checks.iter().filter(|c| c.passed()).count();
+    let cached = checks.iter().filter(|c| c.cached).count();
+    writeln!(out, "{passed}/{} passed, {cached} from cache", checks.len()).unwrap();
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::verify::*;
+
+    #[test]
+    fn it_works() {
+        let answers = parse_answers("# comment\n2022 1 1 24000\n\n2022 10 2 #.\\n.#\n");
+        assert_eq!(answers[&(2022, 1, 1)], Answer::Number(24000));
+        assert_eq!(answers[&(2022, 10, 2)], Answer::Text("#.\n.#".to_string()));
+
+        let wrong = Check {
+            year: 2022,
+            day: 1,
+            part: 1,
+            expected: answers.get(&(2022, 1, 1)).cloned(),
+            outcome: Outcome::Solved(Answer::Number(1)),
+            elapsed: Duration::ZERO,
+            cached: true,
+        };
+        assert_eq!(wrong.status(), "WRONG");
+        assert!(report(&[wrong]).contains("got 1, expected 24000"));
+    }
+}
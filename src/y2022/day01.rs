@@ -0,0 +1,383 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+use std::fmt::Write;
+use std::io::BufRead;
+
+use crate::input;
+use crate::inspect::Inspection;
+use crate::parse::{self, ParseError, ParseResult};
+
+pub const INPUT: &str = "inputs/2022/day01.txt";
+
+/// How many of the best-stocked elves part 2 adds up.
+pub const TOP: usize = 3;
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+/// What one elf carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    pub calories: u32,
+    pub items: usize,
+}
+
+/// The inventories of the elves, read one line at a time so the input never has to fit in
+/// memory. Lines are trimmed, so unnormalised input like stdin works too.
+pub struct Elves<R> {
+    reader: R,
+    buffer: String,
+    line: usize,
+}
+
+pub fn elves<R: BufRead>(reader: R) -> Elves<R> {
+    Elves {
+        reader,
+        buffer: String::new(),
+        line: 0,
+    }
+}
+
+/// The calorie total of every elf.
+pub fn totals<R: BufRead>(reader: R) -> impl Iterator<Item = ParseResult<u32>> {
+    elves(reader).map(|elf| elf.map(|e| e.calories))
+}
+
+impl<R: BufRead> Elves<R> {
+    /// Reads the next group of lines, skipping blank lines before it.
+    fn next_elf(&mut self) -> ParseResult<Option<Elf>> {
+        let mut elf = None;
+        loop {
+            self.buffer.clear();
+            let read = self.reader.read_line(&mut self.buffer).map_err(|e| {
+                ParseError::new(format!("could not read the input: {e}")).on_line(self.line + 1)
+            })?;
+            if read == 0 {
+                return Ok(elf);
+            }
+            self.line += 1;
+            let line = self.buffer.trim_start_matches('\u{feff}').trim();
+            if line.is_empty() {
+                if elf.is_some() {
+                    return Ok(elf);
+                }
+                continue;
+            }
+            let calories: u32 = parse::number(line).map_err(|e| e.on_line(self.line))?;
+            let Elf {
+                calories: sum,
+                items,
+            } = elf.unwrap_or(Elf {
+                calories: 0,
+                items: 0,
+            });
+            let sum = sum
+                .checked_add(calories)
+                .ok_or_else(|| ParseError::new("too many calories").on_line(self.line))?;
+            elf = Some(Elf {
+                calories: sum,
+                items: items + 1,
+            });
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = ParseResult<Elf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_elf().transpose()
+    }
+}
+
+/// The `k` largest totals, largest first. Only `k` totals are kept at any time, so memory does
+/// not grow with the number of elves.
+pub fn top_k(
+    totals: impl IntoIterator<Item = ParseResult<u32>>,
+    k: usize,
+) -> ParseResult<Vec<u32>> {
+    let mut heap = BinaryHeap::new();
+    for total in totals {
+        heap.push(Reverse(total?));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    Ok(heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(t)| t)
+        .collect())
+}
+
+pub(crate) fn parse_input(contents: &str) -> ParseResult<Vec<u32>> {
+    totals(contents.as_bytes()).collect()
+}
+
+/// The percentiles [`Statistics`] reports, besides the median.
+pub const PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RankedElf {
+    /// Position in the input, starting at 1.
+    pub index: usize,
+    /// 1 for the most calories. Elves with the same total share a rank.
+    pub rank: usize,
+    pub calories: u32,
+    pub items: usize,
+}
+
+/// Per-elf analytics of the inventories, beyond the sums the puzzle asks for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statistics {
+    /// Every elf in input order.
+    pub elves: Vec<RankedElf>,
+    pub mean: f64,
+    pub median: f64,
+    /// The total at each of [`PERCENTILES`], by the nearest-rank method.
+    pub percentiles: Vec<(u8, u32)>,
+    /// How many elves carry each number of items.
+    pub histogram: BTreeMap<usize, usize>,
+}
+
+impl Statistics {
+    pub fn new(elves: impl IntoIterator<Item = ParseResult<Elf>>) -> ParseResult<Self> {
+        let elves: Vec<Elf> = elves.into_iter().collect::<ParseResult<_>>()?;
+        if elves.is_empty() {
+            return Err(ParseError::new("there are no elves"));
+        }
+
+        let mut order: Vec<usize> = (0..elves.len()).collect();
+        order.sort_by_key(|&i| Reverse(elves[i].calories));
+        let mut ranks = vec![0; elves.len()];
+        for (position, &i) in order.iter().enumerate() {
+            ranks[i] = match position.checked_sub(1).map(|p| order[p]) {
+                Some(previous) if elves[previous].calories == elves[i].calories => ranks[previous],
+                _ => position + 1,
+            };
+        }
+
+        let mut sorted: Vec<u32> = elves.iter().map(|e| e.calories).collect();
+        sorted.sort_unstable();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2] as f64
+        } else {
+            (sorted[n / 2 - 1] as f64 + sorted[n / 2] as f64) / 2.0
+        };
+        let percentile = |p: u8| sorted[(p as usize * n).div_ceil(100).max(1) - 1];
+
+        let mut histogram = BTreeMap::new();
+        for elf in &elves {
+            *histogram.entry(elf.items).or_insert(0) += 1;
+        }
+
+        Ok(Statistics {
+            mean: sorted.iter().map(|&c| c as f64).sum::<f64>() / n as f64,
+            median,
+            percentiles: PERCENTILES.iter().map(|&p| (p, percentile(p))).collect(),
+            histogram,
+            elves: elves
+                .iter()
+                .zip(ranks)
+                .enumerate()
+                .map(|(i, (elf, rank))| RankedElf {
+                    index: i + 1,
+                    rank,
+                    calories: elf.calories,
+                    items: elf.items,
+                })
+                .collect(),
+        })
+    }
+
+    /// The elves from most to fewest calories, earlier elves first among equals.
+    pub fn ranking(&self) -> Vec<RankedElf> {
+        let mut ranking = self.elves.clone();
+        ranking.sort_by_key(|e| (e.rank, e.index));
+        ranking
+    }
+
+    /// One row per elf, in input order.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("elf,calories,items,rank\n");
+        for elf in &self.elves {
+            writeln!(
+                out,
+                "{},{},{},{}",
+                elf.index, elf.calories, elf.items, elf.rank
+            )
+            .unwrap();
+        }
+        out
+    }
+
+    /// The summary statistics and the histogram as `statistic,value` rows.
+    pub fn summary_csv(&self) -> String {
+        let mut out = String::from("statistic,value\n");
+        writeln!(out, "elves,{}", self.elves.len()).unwrap();
+        writeln!(out, "mean,{}", self.mean).unwrap();
+        writeln!(out, "median,{}", self.median).unwrap();
+        for (p, value) in &self.percentiles {
+            writeln!(out, "p{p},{value}").unwrap();
+        }
+        for (items, count) in &self.histogram {
+            writeln!(out, "elves with {items} items,{count}").unwrap();
+        }
+        out
+    }
+}
+
+/// The statistics of the inventories, as shown by `inspect`.
+pub fn inspect(input: &str) -> Inspection {
+    let mut inspection = Inspection::default();
+    let statistics = match Statistics::new(elves(input.as_bytes())) {
+        Ok(statistics) => statistics,
+        Err(e) => {
+            inspection.warn(format!("the input does not parse: {e}"));
+            return inspection;
+        }
+    };
+    inspection.fact("elves", statistics.elves.len());
+    inspection.fact("mean", format!("{:.1}", statistics.mean));
+    inspection.fact("median", statistics.median);
+    let percentiles: Vec<String> = statistics
+        .percentiles
+        .iter()
+        .map(|(p, value)| format!("{p}%: {value}"))
+        .collect();
+    inspection.fact("percentiles", percentiles.join(", "));
+    let best: Vec<String> = statistics
+        .ranking()
+        .iter()
+        .take(TOP)
+        .map(|e| format!("elf {} ({})", e.index, e.calories))
+        .collect();
+    inspection.fact("most calories", best.join(", "));
+    let mut histogram = String::new();
+    let widest = statistics.histogram.values().max().copied().unwrap_or(1);
+    for (items, count) in &statistics.histogram {
+        let bar = "#".repeat((count * 40).div_ceil(widest));
+        writeln!(histogram, "{items:>4} {bar} {count}").unwrap();
+    }
+    inspection.fact("items per elf", histogram.trim_end());
+    inspection
+}
+
+fn parse_input_imp(contents: &str) -> Vec<u32> {
+    let mut result: Vec<u32> = vec![0];
+    for content in contents.lines() {
+        match content.parse::<u32>() {
+            Ok(n) => {
+                let len = result.len();
+                result[len - 1] += n;
+            }
+            Err(_) => {
+                result.push(0);
+            }
+        }
+    }
+    result
+}
+
+pub fn part1(input: &str) -> u32 {
+    top_k(totals(input.as_bytes()), 1).unwrap().iter().sum()
+}
+
+pub fn solve1() {
+    let result: u32 = top_k(totals(input::open(INPUT)), 1).unwrap().iter().sum();
+    println!("{result}");
+}
+
+pub fn solve1_imp() {
+    let mut result = 0;
+    let input = parse_input_imp(&read_input());
+    for n in input {
+        result = n.max(result)
+    }
+    println!("{result}")
+}
+
+pub fn part2(input: &str) -> u32 {
+    top_k(totals(input.as_bytes()), TOP).unwrap().iter().sum()
+}
+
+pub fn solve2() {
+    let result: u32 = top_k(totals(input::open(INPUT)), TOP).unwrap().iter().sum();
+    println!("{result}");
+}
+
+pub fn solve2_imp() {
+    let mut tmp = [0, 0, 0, 0];
+    let input = parse_input_imp(&read_input());
+    for n in input {
+        tmp[0] = n;
+        tmp.sort();
+    }
+    let result = tmp[1] + tmp[2] + tmp[3];
+    println!("{result}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day01::*;
+
+    #[test]
+    fn inspection() {
+        let inspect = crate::inspect::inspector(2022, 1).unwrap();
+        let inspection = inspect("1000\n2000\n\n3000\n\n4000\n5000\n6000\n");
+        assert!(inspection.warnings().is_empty());
+        assert_eq!(
+            inspection.to_string(),
+            "elves          3\n\
+             mean           7000.0\n\
+             median         3000\n\
+             percentiles    10%: 3000, 25%: 3000, 75%: 15000, 90%: 15000, 99%: 15000\n\
+             most calories  elf 3 (15000), elf 1 (3000), elf 2 (3000)\n\
+             items per elf:\n   \
+             1 ######################################## 1\n   \
+             2 ######################################## 1\n   \
+             3 ######################################## 1\n"
+        );
+    }
+
+    #[test]
+    fn statistics() {
+        let input = "1\n2\n\n10\n\n3\n\n10\n\n4\n5\n6\n";
+        let statistics = Statistics::new(elves(input.as_bytes())).unwrap();
+        let ranks: Vec<usize> = statistics.elves.iter().map(|e| e.rank).collect();
+        assert_eq!(ranks, vec![4, 2, 4, 2, 1]);
+        let ranking: Vec<usize> = statistics.ranking().iter().map(|e| e.index).collect();
+        assert_eq!(ranking, vec![5, 2, 4, 1, 3]);
+        assert_eq!(statistics.mean, 8.2);
+        assert_eq!(statistics.median, 10.0);
+        assert_eq!(statistics.percentiles[0], (10, 3));
+        assert_eq!(statistics.percentiles[4], (99, 15));
+        assert_eq!(statistics.histogram[&1], 3);
+        assert!(statistics
+            .to_csv()
+            .starts_with("elf,calories,items,rank\n1,3,2,4\n"));
+        assert!(Statistics::new(elves("".as_bytes())).is_err());
+    }
+
+    #[test]
+    fn it_works() {
+        let input = "1000\r\n2000\n\n\n4000\n\n5000\n6000\n \n7000\n";
+        let all: Vec<u32> = totals(input.as_bytes()).map(Result::unwrap).collect();
+        assert_eq!(all, vec![3000, 4000, 11000, 7000]);
+        assert_eq!(top_k(totals(input.as_bytes()), 2), Ok(vec![11000, 7000]));
+        assert_eq!(top_k(totals(input.as_bytes()), 9).unwrap().len(), 4);
+        assert_eq!(
+            top_k(totals(input.as_bytes()), usize::MAX).unwrap().len(),
+            4
+        );
+        assert_eq!(top_k(totals(input.as_bytes()), 0), Ok(vec![]));
+        assert_eq!(
+            top_k(totals("1\n\nx\n".as_bytes()), 1)
+                .unwrap_err()
+                .to_string(),
+            "line 3: expected a number, found \"x\""
+        );
+    }
+}
@@ -0,0 +1,441 @@
+use std::cmp::Reverse;
+use std::fmt::Write;
+
+use itertools::Itertools;
+
+use crate::input;
+use crate::inspect::Inspection;
+use crate::parse::{self, ParseError, ParseResult};
+
+/// A shape of a [`Game`], by its position in the game's cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Shape(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+impl Outcome {
+    fn index(self) -> usize {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 1,
+            Outcome::Win => 2,
+        }
+    }
+}
+
+/// The game of the strategy guide, in the format [`Game::parse`] reads.
+pub const ROCK_PAPER_SCISSORS: &str = "\
+# Rock paper scissors, as played in the strategy guide.
+shape Rock A X 1
+shape Paper B Y 2
+shape Scissors C Z 3
+loss 0 X
+draw 3 Y
+win 6 Z
+";
+
+/// A hand game of an odd number of shapes in a cycle, where every shape beats the half of the
+/// others right before it and loses to the half right after it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    names: Vec<String>,
+    /// The codes of the shapes in the opponent's column of a guide.
+    theirs: Vec<String>,
+    /// The codes of the shapes in our column, when it is read as shapes.
+    ours: Vec<String>,
+    /// What playing each shape scores.
+    points: Vec<u32>,
+    /// Score and code of a loss, a draw and a win, in that order.
+    outcomes: [(u32, String); 3],
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Game::parse(ROCK_PAPER_SCISSORS).expect("the built-in game should parse")
+    }
+}
+
+impl Game {
+    /// Reads a game from lines of
+    ///
+    /// ```text
+    /// shape NAME THEIR_CODE OUR_CODE POINTS
+    /// loss|draw|win POINTS CODE
+    /// ```
+    ///
+    /// with the shapes in the order of the cycle. Lines starting with `#` are comments.
+    pub fn parse(text: &str) -> ParseResult<Game> {
+        let mut game = Game {
+            names: vec![],
+            theirs: vec![],
+            ours: vec![],
+            points: vec![],
+            outcomes: Default::default(),
+        };
+        let mut seen = [false; 3];
+        parse::lines(text, |line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields[..] {
+                [first, ..] if first.starts_with('#') => {}
+                ["shape", name, theirs, ours, points] => {
+                    if game.theirs.iter().any(|c| c == theirs)
+                        || game.ours.iter().any(|c| c == ours)
+                    {
+                        return Err(ParseError::new(format!("the codes of {name} are taken")));
+                    }
+                    game.names.push(name.to_string());
+                    game.theirs.push(theirs.to_string());
+                    game.ours.push(ours.to_string());
+                    game.points.push(parse::number(points)?);
+                }
+                [outcome @ ("loss" | "draw" | "win"), points, code] => {
+                    let index = ["loss", "draw", "win"]
+                        .iter()
+                        .position(|o| *o == outcome)
+                        .unwrap();
+                    if seen[index] {
+                        return Err(ParseError::new(format!("a {outcome} is already scored")));
+                    }
+                    if game.outcomes.iter().any(|(_, c)| c == code) {
+                        return Err(ParseError::new(format!("the code {code} is taken")));
+                    }
+                    seen[index] = true;
+                    game.outcomes[index] = (parse::number(points)?, code.to_string());
+                }
+                _ => return Err(ParseError::new(format!("unexpected line {line:?}"))),
+            }
+            Ok(())
+        })?;
+        if game.names.len() < 3 || game.names.len().is_multiple_of(2) {
+            return Err(ParseError::new(format!(
+                "a cyclic game needs an odd number of at least three shapes, found {}",
+                game.names.len()
+            )));
+        }
+        if seen.contains(&false) {
+            return Err(ParseError::new(
+                "the game should score a loss, a draw and a win",
+            ));
+        }
+        Ok(game)
+    }
+
+    pub fn load(path: &str) -> ParseResult<Game> {
+        Game::parse(&input::load(path))
+    }
+
+    pub fn shapes(&self) -> impl Iterator<Item = Shape> {
+        (0..self.names.len()).map(Shape)
+    }
+
+    pub fn name(&self, shape: Shape) -> &str {
+        &self.names[shape.0]
+    }
+
+    pub fn fight(&self, us: Shape, them: Shape) -> Outcome {
+        let n = self.names.len();
+        match (us.0 + n - them.0) % n {
+            0 => Outcome::Draw,
+            d if d <= n / 2 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    /// The shape to play against `them` for the given outcome. In games of more than three
+    /// shapes several do, and the one scoring most is played.
+    pub fn yields(&self, them: Shape, outcome: Outcome) -> Shape {
+        self.shapes()
+            .filter(|us| self.fight(*us, them) == outcome)
+            .max_by_key(|us| (self.points[us.0], Reverse(us.0)))
+            .expect("every outcome should be possible")
+    }
+
+    /// What we score playing `us` against `them`.
+    pub fn score(&self, us: Shape, them: Shape) -> u32 {
+        self.points[us.0] + self.outcomes[self.fight(us, them).index()].0
+    }
+
+    fn code<T>(codes: &[String], code: &str, found: impl Fn(usize) -> T) -> ParseResult<T> {
+        codes
+            .iter()
+            .position(|c| c == code)
+            .map(found)
+            .ok_or_else(|| ParseError::new(format!("unknown code {code:?}")))
+    }
+
+    /// Reads a guide whose right column is the shape we play.
+    pub fn read_shapes(&self, guide: &str) -> ParseResult<Vec<(Shape, Shape)>> {
+        parse::lines(guide, |line| {
+            let (left, right) = parse::split_once(line, " ")?;
+            Ok((
+                Game::code(&self.theirs, left, Shape)?,
+                Game::code(&self.ours, right, Shape)?,
+            ))
+        })
+    }
+
+    /// Reads a guide whose right column is the outcome we should get.
+    pub fn read_outcomes(&self, guide: &str) -> ParseResult<Vec<(Shape, Outcome)>> {
+        let codes: Vec<String> = self.outcomes.iter().map(|(_, c)| c.clone()).collect();
+        parse::lines(guide, |line| {
+            let (left, right) = parse::split_once(line, " ")?;
+            let outcome = Game::code(&codes, right, |i| {
+                [Outcome::Loss, Outcome::Draw, Outcome::Win][i]
+            })?;
+            Ok((Game::code(&self.theirs, left, Shape)?, outcome))
+        })
+    }
+}
+
+pub const INPUT: &str = "inputs/2022/day02.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+pub(crate) fn parse_input(contents: &str) -> ParseResult<Vec<(Shape, Shape)>> {
+    Game::default().read_shapes(contents)
+}
+
+/// Scores a guide whose right column is the shape we play.
+pub fn score_shapes(game: &Game, guide: &str) -> ParseResult<u32> {
+    let rounds = game.read_shapes(guide)?;
+    Ok(rounds.iter().map(|&(them, us)| game.score(us, them)).sum())
+}
+
+/// Scores a guide whose right column is the outcome we should get.
+pub fn score_outcomes(game: &Game, guide: &str) -> ParseResult<u32> {
+    let rounds = game.read_outcomes(guide)?;
+    Ok(rounds
+        .iter()
+        .map(|&(them, outcome)| game.score(game.yields(them, outcome), them))
+        .sum())
+}
+
+/// How often the opponent plays each shape in `rounds`, as fractions of all rounds.
+pub fn distribution(game: &Game, rounds: &[(Shape, Shape)]) -> Vec<f64> {
+    let mut counts = vec![0; game.names.len()];
+    for (them, _) in rounds {
+        counts[them.0] += 1;
+    }
+    let total = rounds.len().max(1) as f64;
+    counts.into_iter().map(|c| c as f64 / total).collect()
+}
+
+/// The score playing `us` every round is expected to get per round against an opponent playing
+/// by `distribution`.
+pub fn expected_score(game: &Game, us: Shape, distribution: &[f64]) -> f64 {
+    game.shapes()
+        .map(|them| distribution[them.0] * game.score(us, them) as f64)
+        .sum()
+}
+
+/// The single shape that maximises the expected score against `distribution`, with that score.
+pub fn best_response(game: &Game, distribution: &[f64]) -> (Shape, f64) {
+    game.shapes()
+        .map(|us| (us, expected_score(game, us, distribution)))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("a game has shapes")
+}
+
+/// Every way of reading our column's codes as shapes, with the score of the guide under it,
+/// best first. `mapping[i]` is the shape the `i`th code of the game stands for.
+pub fn column_mappings(game: &Game, guide: &str) -> ParseResult<Vec<(Vec<Shape>, u32)>> {
+    let rounds = game.read_shapes(guide)?;
+    let mut mappings: Vec<(Vec<Shape>, u32)> = game
+        .shapes()
+        .permutations(game.names.len())
+        .map(|mapping| {
+            let score = rounds
+                .iter()
+                .map(|(them, code)| game.score(mapping[code.0], *them))
+                .sum();
+            (mapping, score)
+        })
+        .collect();
+    mappings.sort_by_key(|(_, score)| Reverse(*score));
+    Ok(mappings)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub name: String,
+    pub score: u32,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+/// Plays every guide against every other one, each playing the shapes of its right column in
+/// order for as many rounds as the shorter guide has. Returns the standings, best first.
+pub fn tournament(game: &Game, players: &[(&str, Vec<Shape>)]) -> Vec<Standing> {
+    let mut standings: Vec<Standing> = players
+        .iter()
+        .map(|(name, _)| Standing {
+            name: name.to_string(),
+            score: 0,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+        })
+        .collect();
+    for (a, b) in (0..players.len()).tuple_combinations() {
+        for (&us, &them) in players[a].1.iter().zip(&players[b].1) {
+            for (player, mine, theirs) in [(a, us, them), (b, them, us)] {
+                let standing = &mut standings[player];
+                standing.score += game.score(mine, theirs);
+                match game.fight(mine, theirs) {
+                    Outcome::Win => standing.wins += 1,
+                    Outcome::Draw => standing.draws += 1,
+                    Outcome::Loss => standing.losses += 1,
+                }
+            }
+        }
+    }
+    standings.sort_by_key(|s| Reverse(s.score));
+    standings
+}
+
+/// The shapes a guide plays, reading its right column as shapes.
+pub fn moves(game: &Game, guide: &str) -> ParseResult<Vec<Shape>> {
+    Ok(game
+        .read_shapes(guide)?
+        .into_iter()
+        .map(|(_, us)| us)
+        .collect())
+}
+
+/// What the opponent plays, how best to answer it, and how every reading of the ambiguous right
+/// column would score.
+pub fn inspect(input: &str) -> Inspection {
+    let mut inspection = Inspection::default();
+    let game = Game::default();
+    let (rounds, mappings) = match (game.read_shapes(input), column_mappings(&game, input)) {
+        (Ok(rounds), Ok(mappings)) => (rounds, mappings),
+        (Err(e), _) | (_, Err(e)) => {
+            inspection.warn(format!("the input does not parse: {e}"));
+            return inspection;
+        }
+    };
+    inspection.fact("rounds", rounds.len());
+    let distribution = distribution(&game, &rounds);
+    let played: Vec<String> = game
+        .shapes()
+        .map(|s| format!("{} {:.1}%", game.name(s), 100.0 * distribution[s.0]))
+        .collect();
+    inspection.fact("opponent plays", played.join(", "));
+    let (best, expected) = best_response(&game, &distribution);
+    inspection.fact(
+        "best response",
+        format!(
+            "always {}, {expected:.2} points a round, {:.0} in total",
+            game.name(best),
+            expected * rounds.len() as f64
+        ),
+    );
+    let mut table = String::new();
+    for (mapping, score) in &mappings {
+        let reading: Vec<String> = mapping
+            .iter()
+            .zip(&game.ours)
+            .map(|(shape, code)| format!("{code}={}", game.name(*shape)))
+            .collect();
+        writeln!(table, "{:>8}  {}", score, reading.join(" ")).unwrap();
+    }
+    inspection.fact("column readings", table.trim_end());
+    inspection
+}
+
+pub fn part1(input: &str) -> u32 {
+    score_shapes(&Game::default(), input).unwrap()
+}
+
+pub fn part2(input: &str) -> u32 {
+    score_outcomes(&Game::default(), input).unwrap()
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input());
+    println!("{result}");
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day02::*;
+
+    #[test]
+    fn inspection() {
+        let inspect = crate::inspect::inspector(2022, 2).unwrap();
+        let inspection = inspect("A Y\nB X\nC Z\nA X\n");
+        assert!(inspection.warnings().is_empty());
+        assert_eq!(
+            inspection.to_string(),
+            "rounds           4\n\
+             opponent plays   Rock 50.0%, Paper 25.0%, Scissors 25.0%\n\
+             best response    always Paper, 5.75 points a round, 23 in total\n\
+             column readings:\n      \
+             27  X=Scissors Y=Paper Z=Rock\n      \
+             23  X=Paper Y=Rock Z=Scissors\n      \
+             23  X=Paper Y=Scissors Z=Rock\n      \
+             19  X=Rock Y=Paper Z=Scissors\n      \
+             18  X=Scissors Y=Rock Z=Paper\n      \
+             10  X=Rock Y=Scissors Z=Paper\n"
+        );
+    }
+
+    #[test]
+    fn it_works() {
+        let guide = "A Y\nB X\nC Z\n";
+        assert_eq!(part1(guide), 15);
+        assert_eq!(part2(guide), 12);
+
+        let game = Game::load("inputs/2022/games/rpsls.txt").unwrap();
+        let [rock, spock, paper, lizard, scissors] = [0, 1, 2, 3, 4].map(Shape);
+        assert_eq!(game.fight(spock, rock), Outcome::Win);
+        assert_eq!(game.fight(spock, scissors), Outcome::Win);
+        assert_eq!(game.fight(lizard, spock), Outcome::Win);
+        assert_eq!(game.fight(paper, lizard), Outcome::Loss);
+        assert_eq!(game.yields(rock, Outcome::Win), paper);
+        // Spock vaporises rock and lizard poisons Spock.
+        assert_eq!(score_shapes(&game, "A W\nB Y\n"), Ok(2 + 6 + 4 + 6));
+        assert_eq!(score_outcomes(&game, "A Z\nE Y\n"), Ok(3 + 6 + 5 + 3));
+
+        let twice = "shape A A X 1\nshape B B Y 2\nshape C C Z 3\n\
+                     loss 0 X\nloss 1 Q\ndraw 3 Y\nwin 6 Z\n";
+        assert_eq!(
+            Game::parse(twice).unwrap_err().to_string(),
+            "line 5: a loss is already scored"
+        );
+
+        let game = Game::default();
+        let rounds = game.read_shapes(guide).unwrap();
+        let distribution = distribution(&game, &rounds);
+        assert_eq!(distribution, vec![1.0 / 3.0; 3]);
+        let (best, expected) = best_response(&game, &distribution);
+        assert_eq!((game.name(best), expected), ("Scissors", 6.0));
+        let mappings = column_mappings(&game, guide).unwrap();
+        assert_eq!(mappings.len(), 6);
+        assert!(mappings.contains(&(vec![Shape(0), Shape(1), Shape(2)], 15)));
+        assert!(mappings.windows(2).all(|w| w[0].1 >= w[1].1));
+
+        let rock = moves(&game, "A X\nA X\n").unwrap();
+        let paper = moves(&game, "A Y\nA Y\n").unwrap();
+        let standings = tournament(&game, &[("rock", rock), ("paper", paper)]);
+        assert_eq!(standings[0].name, "paper");
+        assert_eq!((standings[0].score, standings[0].wins), (16, 2));
+        assert_eq!((standings[1].score, standings[1].losses), (2, 2));
+
+        let even = "shape Rock A X 1\nshape Paper B Y 2\nloss 0 X\ndraw 3 Y\nwin 6 Z\n";
+        assert!(Game::parse(even).is_err());
+    }
+}
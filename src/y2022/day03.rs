@@ -0,0 +1,450 @@
+use std::fmt::{self, Write};
+
+use crate::input;
+use crate::inspect::Inspection;
+use crate::parse::{self, ParseError, ParseResult};
+
+pub const INPUT: &str = "inputs/2022/day03.txt";
+
+/// How many elves share a badge.
+pub const GROUP_SIZE: usize = 3;
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+/// A set of item types, bit `p - 1` standing for the item of priority `p`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const ALL: ItemSet = ItemSet((1 << 52) - 1);
+
+    /// Reads a set from a list of items, which must be ASCII letters.
+    pub fn parse(items: &str) -> ParseResult<ItemSet> {
+        let mut set = ItemSet::default();
+        for item in items.chars() {
+            set.insert(item)?;
+        }
+        Ok(set)
+    }
+
+    /// Fails for anything but an ASCII letter.
+    pub fn insert(&mut self, item: char) -> ParseResult<()> {
+        self.0 |= 1 << (checked_priority(item)? - 1);
+        Ok(())
+    }
+
+    /// Whether the set holds `item`, which it never does if `item` is not a letter.
+    pub fn contains(self, item: char) -> bool {
+        priority(item).is_some_and(|p| self.0 & (1 << (p - 1)) != 0)
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn priority_sum(self) -> u32 {
+        self.priorities().sum()
+    }
+
+    fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |p| self.0 & (1 << (p - 1)) != 0)
+    }
+
+    /// The items in the set, by priority.
+    pub fn items(self) -> impl Iterator<Item = char> {
+        self.priorities().map(item)
+    }
+}
+
+impl fmt::Display for ItemSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.items().try_for_each(|item| write!(f, "{item}"))
+    }
+}
+
+/// The priority of an item, `a` to `z` being 1 to 26 and `A` to `Z` 27 to 52. Anything but an
+/// ASCII letter is no item.
+pub fn priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 96),
+        'A'..='Z' => Some(item as u32 - 38),
+        _ => None,
+    }
+}
+
+fn checked_priority(item: char) -> ParseResult<u32> {
+    priority(item).ok_or_else(|| ParseError::new(format!("unexpected item {item:?}")))
+}
+
+fn item(priority: u32) -> char {
+    let code = if priority > 26 { 38 } else { 96 };
+    char::from_u32(priority + code).unwrap()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    /// The line of the input the rucksack is on.
+    pub line: usize,
+    pub items: String,
+}
+
+impl Rucksack {
+    /// The two halves of the items, once every item is checked to be a letter and so a single byte.
+    pub fn compartments(&self) -> ParseResult<(&str, &str)> {
+        for item in self.items.chars() {
+            checked_priority(item).map_err(|e| e.on_line(self.line))?;
+        }
+        if !self.items.len().is_multiple_of(2) {
+            return Err(
+                ParseError::new("the compartments cannot be the same size").on_line(self.line)
+            );
+        }
+        Ok(self.items.split_at(self.items.len() / 2))
+    }
+
+    pub fn item_set(&self) -> ParseResult<ItemSet> {
+        ItemSet::parse(&self.items).map_err(|e| e.on_line(self.line))
+    }
+
+    /// The one item type in both compartments.
+    pub fn misplaced(&self) -> ParseResult<char> {
+        let (left, right) = self.compartments()?;
+        let shared = ItemSet::parse(left)?.intersection(ItemSet::parse(right)?);
+        only_item(shared)
+            .map_err(|e| ParseError::new(format!("the compartments {e}")).on_line(self.line))
+    }
+}
+
+/// The item of a set with exactly one, or what is wrong with the set.
+fn only_item(set: ItemSet) -> Result<char, String> {
+    match set.len() {
+        0 => Err("share no item".to_string()),
+        1 => Ok(set.items().next().unwrap()),
+        n => Err(format!("share {n} items ({set})")),
+    }
+}
+
+/// The one item type every rucksack of a group carries.
+pub fn badge(group: &[Rucksack]) -> ParseResult<char> {
+    let shared = group.iter().try_fold(ItemSet::ALL, |shared, r| {
+        Ok::<_, ParseError>(shared.intersection(r.item_set()?))
+    })?;
+    only_item(shared).map_err(|e| {
+        let lines: Vec<String> = group.iter().map(|r| r.line.to_string()).collect();
+        ParseError::new(format!("the rucksacks on lines {} {e}", lines.join(", ")))
+    })
+}
+
+pub(crate) fn parse_input(contents: &str) -> ParseResult<Vec<Rucksack>> {
+    parse::numbered_lines(contents, |line, items| {
+        match items.chars().find(|c| !c.is_ascii_alphabetic()) {
+            Some(c) => Err(ParseError::new(format!("unexpected item {c:?}"))),
+            None => Ok(Rucksack {
+                line,
+                items: items.to_owned(),
+            }),
+        }
+    })
+}
+
+/// The priorities of the items in both compartments of every rucksack, added up.
+pub fn misplaced_priorities(rucksacks: &[Rucksack]) -> ParseResult<u32> {
+    rucksacks
+        .iter()
+        .map(|r| checked_priority(r.misplaced()?))
+        .sum()
+}
+
+/// The priorities of the badges of every group of `group_size` consecutive rucksacks, added up.
+pub fn badge_priorities(rucksacks: &[Rucksack], group_size: usize) -> ParseResult<u32> {
+    if group_size == 0 || !rucksacks.len().is_multiple_of(group_size) {
+        return Err(ParseError::new(format!(
+            "{} rucksacks cannot be split into groups of {group_size}",
+            rucksacks.len()
+        )));
+    }
+    rucksacks
+        .chunks(group_size)
+        .map(|group| checked_priority(badge(group)?))
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// Moving `count` items of one type out of the compartment on side `from` into the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transfer {
+    pub item: char,
+    pub count: usize,
+    pub from: Side,
+}
+
+/// The fewest item moves that leave no item type in both compartments of a rucksack, with the
+/// compartments the same size afterwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub line: usize,
+    pub transfers: Vec<Transfer>,
+}
+
+impl Plan {
+    pub fn moves(&self) -> usize {
+        self.transfers.iter().map(|t| t.count).sum()
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        if self.transfers.is_empty() {
+            return write!(f, "nothing to move");
+        }
+        for (i, t) in self.transfers.iter().enumerate() {
+            let direction = match t.from {
+                Side::Left => "left to right",
+                Side::Right => "right to left",
+            };
+            let separator = if i == 0 { "" } else { ", " };
+            write!(f, "{separator}move {} {} {direction}", t.count, t.item)?;
+        }
+        Ok(())
+    }
+}
+
+/// How many items of each priority a compartment holds.
+fn counts(items: &str) -> ParseResult<[usize; 52]> {
+    let mut counts = [0; 52];
+    for item in items.chars() {
+        counts[checked_priority(item)? as usize - 1] += 1;
+    }
+    Ok(counts)
+}
+
+/// Plans the moves for one rucksack. Every item type ends up on one side, so this picks the
+/// types for the left compartment that fill exactly half of the rucksack while leaving the
+/// fewest items to move, by dynamic programming over the item types and the left size.
+pub fn rebalance(rucksack: &Rucksack) -> ParseResult<Plan> {
+    let (left, right) = rucksack.compartments()?;
+    let (left, right) = (counts(left)?, counts(right)?);
+    let half = rucksack.items.len() / 2;
+    let types: Vec<usize> = (0..52).filter(|&t| left[t] + right[t] > 0).collect();
+
+    // best[i][s]: the fewest moves placing the first `i` types with `s` items on the left.
+    let mut best = vec![vec![None; half + 1]; types.len() + 1];
+    best[0][0] = Some(0);
+    for (i, &t) in types.iter().enumerate() {
+        let total = left[t] + right[t];
+        for s in 0..=half {
+            let to_right = best[i][s].map(|moves| moves + left[t]);
+            let to_left = s
+                .checked_sub(total)
+                .and_then(|rest| best[i][rest])
+                .map(|moves| moves + right[t]);
+            best[i + 1][s] = match (to_left, to_right) {
+                (Some(l), Some(r)) => Some(l.min(r)),
+                (l, r) => l.or(r),
+            };
+        }
+    }
+    if best[types.len()][half].is_none() {
+        return Err(
+            ParseError::new("the item types cannot be split into equal compartments")
+                .on_line(rucksack.line),
+        );
+    }
+
+    let mut transfers = vec![];
+    let mut s = half;
+    for (i, &t) in types.iter().enumerate().rev() {
+        let total = left[t] + right[t];
+        let moves = best[i + 1][s];
+        let stays_right = best[i][s].map(|m| m + left[t]) == moves;
+        let (count, from) = if stays_right {
+            (left[t], Side::Left)
+        } else {
+            s -= total;
+            (right[t], Side::Right)
+        };
+        if count > 0 {
+            transfers.push(Transfer {
+                item: item(t as u32 + 1),
+                count,
+                from,
+            });
+        }
+    }
+    transfers.reverse();
+    Ok(Plan {
+        line: rucksack.line,
+        transfers,
+    })
+}
+
+/// The plans of every rucksack, the ones that cannot be rebalanced turning up as errors.
+pub fn rebalance_all(rucksacks: &[Rucksack]) -> Vec<ParseResult<Plan>> {
+    rucksacks.iter().map(rebalance).collect()
+}
+
+/// The move plans of the rucksacks and their totals.
+pub fn inspect(input: &str) -> Inspection {
+    let mut inspection = Inspection::default();
+    let rucksacks = match parse_input(input) {
+        Ok(rucksacks) => rucksacks,
+        Err(e) => {
+            inspection.warn(format!("the input does not parse: {e}"));
+            return inspection;
+        }
+    };
+    let results = rebalance_all(&rucksacks);
+    let plans: Vec<&Plan> = results.iter().filter_map(|r| r.as_ref().ok()).collect();
+    inspection.fact("rucksacks", rucksacks.len());
+    let balanced = plans.iter().filter(|p| p.transfers.is_empty()).count();
+    inspection.fact("already sorted", balanced);
+    inspection.fact("moves", plans.iter().map(|p| p.moves()).sum::<usize>());
+    let mut listing = String::new();
+    for plan in &plans {
+        writeln!(listing, "{plan}").unwrap();
+    }
+    inspection.fact("plans", listing.trim_end());
+    for error in results.iter().filter_map(|r| r.as_ref().err()) {
+        inspection.warn(error.to_string());
+    }
+    inspection
+}
+
+pub fn part1(input: &str) -> u32 {
+    misplaced_priorities(&parse_input(input).unwrap()).unwrap()
+}
+
+pub fn part2(input: &str) -> u32 {
+    badge_priorities(&parse_input(input).unwrap(), GROUP_SIZE).unwrap()
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}")
+}
+
+pub fn solve2() {
+    let result = part2(&read_input());
+    println!("{result}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day03::*;
+
+    #[test]
+    fn inspection() {
+        let inspect = crate::inspect::inspector(2022, 3).unwrap();
+        let inspection = inspect("vJrwpWtwJgWrhcsFMMfFFhFp\nPmmdzqPrVvPwwTWBwg\nabcdef\nabc\n");
+        assert_eq!(
+            inspection.warnings(),
+            ["line 4: the compartments cannot be the same size"]
+        );
+        assert_eq!(
+            inspection.to_string(),
+            "rucksacks       4\n\
+             already sorted  1\n\
+             moves           4\n\
+             plans:\n\
+             line 1: move 1 p right to left, move 1 v left to right\n\
+             line 2: move 1 P right to left, move 1 V left to right\n\
+             line 3: nothing to move\n\
+             warning: line 4: the compartments cannot be the same size\n"
+        );
+    }
+
+    #[test]
+    fn it_works() {
+        let set = ItemSet::parse("vJrwpWtwJgWr").unwrap();
+        assert_eq!(
+            ItemSet::parse("a1").unwrap_err().to_string(),
+            "unexpected item '1'"
+        );
+        assert_eq!(priority('{'), None);
+        assert_eq!(priority('@'), None);
+        assert!(!set.contains('{') && !set.contains('1'));
+        let odd = Rucksack {
+            line: 7,
+            items: "a{b{".to_string(),
+        };
+        assert_eq!(
+            odd.misplaced().unwrap_err().to_string(),
+            "line 7: unexpected item '{'"
+        );
+        assert!(rebalance(&odd).is_err() && badge(&[odd]).is_err());
+        let wide = Rucksack {
+            line: 1,
+            items: "aéb".to_string(),
+        };
+        assert_eq!(
+            wide.misplaced().unwrap_err().to_string(),
+            "line 1: unexpected item 'é'"
+        );
+        assert_eq!(
+            rebalance(&wide).unwrap_err().to_string(),
+            "line 1: unexpected item 'é'"
+        );
+        assert_eq!(set.to_string(), "gprtvwJW");
+        assert!(set.contains('J') && !set.contains('j'));
+        assert_eq!(ItemSet::ALL.len(), 52);
+        assert_eq!(ItemSet::ALL.priority_sum(), (1..=52).sum());
+
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp\n\
+                     jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\n\
+                     PmmdzqPrVvPwwTWBwg\n\
+                     wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\n\
+                     ttgJtRGJQctTZtZT\n\
+                     CrZsJsPPZsGzwwsLwLmpwMDw\n";
+        assert_eq!(part1(input), 157);
+        assert_eq!(part2(input), 70);
+
+        let rucksacks = parse_input(input).unwrap();
+        assert_eq!(
+            badge_priorities(&rucksacks, 2).unwrap_err().to_string(),
+            "the rucksacks on lines 1, 2 share 5 items (frsFM)"
+        );
+        assert!(badge_priorities(&rucksacks, 4).is_err());
+        let plans: Vec<String> = rebalance_all(&rucksacks)
+            .iter()
+            .map(|p| p.as_ref().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            plans[0],
+            "line 1: move 1 p right to left, move 1 v left to right"
+        );
+        assert_eq!(
+            plans[4],
+            "line 5: move 2 t right to left, move 1 G left to right, move 1 R left to right"
+        );
+        let swap = parse_input("aabb\nabab\nabcd\n").unwrap();
+        let moves: Vec<usize> = rebalance_all(&swap)
+            .iter()
+            .map(|p| p.as_ref().unwrap().moves())
+            .collect();
+        assert_eq!(moves, vec![0, 2, 0]);
+        let uneven = parse_input("aaab\n").unwrap();
+        assert!(rebalance(&uneven[0]).is_err());
+
+        let odd = parse_input("ab\n\nabc\n").unwrap();
+        assert_eq!(
+            misplaced_priorities(&odd).unwrap_err().to_string(),
+            "line 1: the compartments share no item"
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap};
+use std::fmt::{self, Write};
+
+use crate::input;
+use crate::inspect::Inspection;
+use crate::interval::{Interval, IntervalSet};
+use crate::parse::{self, ParseError, ParseResult};
+
+/// Parses `START-END`, the sections an elf is assigned.
+fn parse_elf_range(str: &str) -> ParseResult<Interval> {
+    let (l, r) = parse::split_once(str, "-")?;
+    let (start, end): (u32, u32) = (parse::number(l)?, parse::number(r)?);
+    Interval::new(start.into(), end.into())
+        .ok_or_else(|| ParseError::new(format!("the range {str} ends before it starts")))
+}
+
+pub const INPUT: &str = "inputs/2022/day04.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+fn parse_pair(line: &str) -> ParseResult<(Interval, Interval)> {
+    let (l, r) = parse::split_once(line, ",")?;
+    Ok((parse_elf_range(l)?, parse_elf_range(r)?))
+}
+
+pub(crate) fn parse_input(contents: &str) -> ParseResult<Vec<(Interval, Interval)>> {
+    parse::lines(contents, parse_pair)
+}
+
+/// The sections of one elf, named by the line of its pair and its place in the pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Assignment {
+    pub line: usize,
+    /// 1 for the first elf of the pair, 2 for the second.
+    pub elf: usize,
+    pub sections: Interval,
+}
+
+impl fmt::Display for Assignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} elf {} ({})", self.line, self.elf, self.sections)
+    }
+}
+
+/// Every elf's assignment, two per line in input order.
+pub fn assignments(contents: &str) -> ParseResult<Vec<Assignment>> {
+    let pairs = parse::numbered_lines(contents, |line_no, line| Ok((line_no, parse_pair(line)?)))?;
+    Ok(pairs
+        .into_iter()
+        .flat_map(|(line, (l, r))| {
+            [(1, l), (2, r)].map(|(elf, sections)| Assignment {
+                line,
+                elf,
+                sections,
+            })
+        })
+        .collect())
+}
+
+/// How the assignments of all elves overlap, across lines as well as within them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    /// The indices into the assignments of every two elves sharing a section, the earlier one
+    /// first, in the order their overlaps start.
+    pub overlapping: Vec<(usize, usize)>,
+    /// The sections more than one elf cleans.
+    pub shared: IntervalSet,
+    /// The sections between the first and the last assigned one that no elf cleans.
+    pub uncovered: IntervalSet,
+    /// The most elves cleaning any one section.
+    pub depth: usize,
+}
+
+/// Sweeps over the starts and ends of all assignments in order, keeping the elves whose
+/// sections the sweep is in. Apart from listing the overlapping pairs, this takes
+/// O(n log n) for n elves.
+pub fn coverage(assignments: &[Assignment]) -> Coverage {
+    // An assignment leaves the sweep just after its last section, before any that start there.
+    let mut events: Vec<(i64, bool, usize)> = assignments
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| [(a.sections.start, true, i), (a.sections.end + 1, false, i)])
+        .collect();
+    events.sort_unstable();
+
+    let mut active = BTreeSet::new();
+    let mut overlapping = vec![];
+    let mut shared = vec![];
+    let mut shared_since = None;
+    let mut depth = 0;
+    for (position, starts, i) in events {
+        if starts {
+            overlapping.extend(active.iter().map(|&j| (i.min(j), i.max(j))));
+            active.insert(i);
+        } else {
+            active.remove(&i);
+        }
+        depth = depth.max(active.len());
+        match shared_since {
+            None if active.len() > 1 => shared_since = Some(position),
+            Some(since) if active.len() < 2 => {
+                shared.extend(Interval::new(since, position - 1));
+                shared_since = None;
+            }
+            _ => {}
+        }
+    }
+
+    let covered: IntervalSet = assignments.iter().map(|a| a.sections).collect();
+    Coverage {
+        overlapping,
+        shared: shared.into_iter().collect(),
+        uncovered: covered.gaps().collect(),
+        depth,
+    }
+}
+
+/// Cleanup crews for the elves, so that no crew has two elves whose sections overlap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crews {
+    pub count: usize,
+    /// The crew of each assignment, starting at 1.
+    pub crews: Vec<usize>,
+}
+
+/// Hands out crews to the assignments by their first section, giving each the lowest numbered
+/// crew that is done by then. No fewer crews can do, as that many assignments share a section
+/// when the last crew is first needed.
+pub fn crews(assignments: &[Assignment]) -> Crews {
+    let mut order: Vec<usize> = (0..assignments.len()).collect();
+    order.sort_by_key(|&i| assignments[i].sections.start);
+
+    let mut crews = vec![0; assignments.len()];
+    let mut count = 0;
+    let mut busy = BinaryHeap::new();
+    let mut free = BinaryHeap::new();
+    for i in order {
+        let sections = assignments[i].sections;
+        while let Some(&Reverse((end, crew))) = busy.peek() {
+            if end >= sections.start {
+                break;
+            }
+            busy.pop();
+            free.push(Reverse(crew));
+        }
+        let crew = match free.pop() {
+            Some(Reverse(crew)) => crew,
+            None => {
+                count += 1;
+                count
+            }
+        };
+        crews[i] = crew;
+        busy.push(Reverse((sections.end, crew)));
+    }
+    Crews { count, crews }
+}
+
+/// How the assignments of all elves overlap and the crews they need, as shown by `inspect`.
+pub fn inspect(input: &str) -> Inspection {
+    let mut inspection = Inspection::default();
+    let assignments = match assignments(input) {
+        Ok(assignments) => assignments,
+        Err(e) => {
+            inspection.warn(format!("the input does not parse: {e}"));
+            return inspection;
+        }
+    };
+    let coverage = coverage(&assignments);
+    let across = coverage
+        .overlapping
+        .iter()
+        .filter(|&&(a, b)| assignments[a].line != assignments[b].line)
+        .count();
+    inspection.fact("elves", assignments.len());
+    inspection.fact("overlapping pairs", coverage.overlapping.len());
+    inspection.fact("across lines", across);
+    inspection.fact("most elves on a section", coverage.depth);
+    inspection.fact("shared sections", coverage.shared.len());
+    if coverage.uncovered.is_empty() {
+        inspection.fact("uncovered sections", "none");
+    } else {
+        inspection.fact("uncovered sections", &coverage.uncovered);
+    }
+    let crews = crews(&assignments);
+    inspection.fact("crews", crews.count);
+    let mut listing = String::new();
+    for (assignment, crew) in assignments.iter().zip(&crews.crews) {
+        writeln!(listing, "{assignment}: crew {crew}").unwrap();
+    }
+    inspection.fact("crew of each elf", listing.trim_end());
+    inspection
+}
+
+pub fn part1(input: &str) -> u32 {
+    let inputs = parse_input(input).unwrap();
+    let mut result = 0;
+    for (l, r) in inputs {
+        if l.includes(r) || r.includes(l) {
+            result += 1;
+        }
+    }
+    result
+}
+
+pub fn part2(input: &str) -> u32 {
+    let inputs = parse_input(input).unwrap();
+    let mut result = 0;
+    for (l, r) in inputs {
+        if l.overlaps(r) {
+            result += 1;
+        }
+    }
+    result
+}
+
+/// The fewest crews the elves can be split into.
+pub fn part3(input: &str) -> usize {
+    crews(&assignments(input).unwrap()).count
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}")
+}
+
+pub fn solve2() {
+    let result = part2(&read_input());
+    println!("{result}")
+}
+
+pub fn solve3() {
+    let result = part3(&read_input());
+    println!("{result}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day04::*;
+
+    #[test]
+    fn inspection() {
+        let inspect = crate::inspect::inspector(2022, 4).unwrap();
+        let inspection = inspect("2-4,6-8\n3-7,5-5\n12-12,2-3\n");
+        assert!(inspection.warnings().is_empty());
+        assert_eq!(
+            inspection.to_string(),
+            "elves                    6\n\
+             overlapping pairs        5\n\
+             across lines             4\n\
+             most elves on a section  3\n\
+             shared sections          6\n\
+             uncovered sections       9-11\n\
+             crews                    3\n\
+             crew of each elf:\n\
+             line 1 elf 1 (2-4): crew 1\n\
+             line 1 elf 2 (6-8): crew 1\n\
+             line 2 elf 1 (3-7): crew 3\n\
+             line 2 elf 2 (5-5): crew 1\n\
+             line 3 elf 1 (12-12): crew 1\n\
+             line 3 elf 2 (2-3): crew 2\n"
+        );
+    }
+
+    #[test]
+    fn it_works() {
+        let input = "2-4,6-8\n2-3,4-5\n\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n12-12,14-15\n";
+        assert_eq!(part1(input), 2);
+        assert_eq!(part2(input), 4);
+
+        assert!(assignments("1-2,4-3\n").is_err());
+
+        let assignments = assignments(input).unwrap();
+        assert_eq!(assignments[4].to_string(), "line 4 elf 1 (5-7)");
+        let coverage = coverage(&assignments);
+        let mut overlapping = coverage.overlapping.clone();
+        overlapping.sort_unstable();
+        let mut expected = vec![];
+        for (i, a) in assignments.iter().enumerate() {
+            for (j, b) in assignments.iter().enumerate().skip(i + 1) {
+                if a.sections.overlaps(b.sections) {
+                    expected.push((i, j));
+                }
+            }
+        }
+        assert_eq!(overlapping, expected);
+        assert!(expected.contains(&(0, 3)) && !expected.contains(&(0, 1)));
+        assert_eq!(coverage.shared.to_string(), "2-8");
+        assert_eq!(coverage.uncovered.to_string(), "10-11,13-13");
+        assert_eq!(coverage.depth, 8);
+
+        let crews = crews(&assignments);
+        assert_eq!(crews.count, coverage.depth);
+        assert_eq!(crews.crews[..4], [1, 2, 2, 2]);
+        for &(a, b) in &coverage.overlapping {
+            assert_ne!(crews.crews[a], crews.crews[b]);
+        }
+        assert_eq!(part3(input), 8);
+    }
+}
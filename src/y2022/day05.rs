@@ -0,0 +1,614 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt::{self, Write};
+
+use crate::input;
+use crate::inspect::Inspection;
+use crate::parse::{self, ParseError, ParseResult};
+
+/// The label of a crate. Labels are single letters in the puzzle, but may be any word without
+/// brackets.
+pub type Crate = String;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Table {
+    /// The crates of each stack, bottom first.
+    stacks: Vec<Vec<Crate>>,
+}
+
+/// `move COUNT from FROM to TO`, with the stacks numbered from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+    /// Where the instruction is in the input, starting at 1.
+    pub line: usize,
+}
+
+/// Written the way the puzzle input has it, so planned instructions can be read back.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.count, self.from, self.to)
+    }
+}
+
+/// How a crane puts down the crates it moves.
+pub trait Crane {
+    /// Reorders `crates`, lifted off the top of a stack bottom first, into the order they land
+    /// on the other stack, bottom first.
+    fn arrange(&self, crates: &mut [Crate]);
+}
+
+/// Moves one crate at a time, so the crates land in reverse order.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn arrange(&self, crates: &mut [Crate]) {
+        crates.reverse();
+    }
+}
+
+/// Moves all crates at once, so they keep their order.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn arrange(&self, _: &mut [Crate]) {}
+}
+
+/// Any function reordering the lifted crates is a crane too.
+impl<F: Fn(&mut [Crate])> Crane for F {
+    fn arrange(&self, crates: &mut [Crate]) {
+        self(crates)
+    }
+}
+
+impl Table {
+    /// Fails for labels a drawing could not show, which are empty or hold whitespace or
+    /// brackets.
+    pub fn new(stacks: Vec<Vec<Crate>>) -> ParseResult<Self> {
+        for label in stacks.iter().flatten() {
+            if label.is_empty() || label.contains(|c: char| c.is_whitespace() || "[]".contains(c)) {
+                return Err(ParseError::new(format!(
+                    "a crate cannot be labelled {label:?}"
+                )));
+            }
+        }
+        Ok(Table { stacks })
+    }
+
+    /// The crates of each stack, bottom first.
+    pub fn stacks(&self) -> &[Vec<Crate>] {
+        &self.stacks
+    }
+
+    /// Parses a drawing like the one [`Table`] displays as: rows of crates like `[A]`, the top
+    /// row first, above a row numbering the stacks from 1. A crate belongs to the stack whose
+    /// number it is centred closest to, so labels may be wider than the numbers and rows may end
+    /// early. Errors name the line of the drawing.
+    pub fn parse(drawing: &str) -> ParseResult<Table> {
+        let lines: Vec<&str> = drawing.lines().collect();
+        let Some((legend, rows)) = lines.split_last() else {
+            return Err(ParseError::new("expected a drawing of the stacks"));
+        };
+        // Centres are doubled, so they stay whole for words of even width.
+        let mut centres = vec![];
+        for (i, (column, word)) in words(legend).enumerate() {
+            let number: usize = parse::number(word).map_err(|e| e.on_line(lines.len()))?;
+            if number != i + 1 {
+                return Err(ParseError::new(format!(
+                    "expected stack {} next, found stack {number}",
+                    i + 1
+                ))
+                .on_line(lines.len()));
+            }
+            centres.push(2 * column + word.chars().count());
+        }
+        if centres.is_empty() {
+            return Err(ParseError::new("expected stacks above the stack numbers"));
+        }
+
+        let mut stacks = vec![vec![]; centres.len()];
+        for (index, row) in rows.iter().enumerate().rev() {
+            for (column, word) in words(row) {
+                let label = word
+                    .strip_prefix('[')
+                    .and_then(|w| w.strip_suffix(']'))
+                    .filter(|l| !l.is_empty() && !l.contains(['[', ']']))
+                    .ok_or_else(|| {
+                        ParseError::new(format!("expected a crate like [A], found {word:?}"))
+                            .on_line(index + 1)
+                    })?;
+                let centre = 2 * column + word.chars().count();
+                let stack = (0..centres.len())
+                    .min_by_key(|&i| centres[i].abs_diff(centre))
+                    .unwrap();
+                if stacks[stack].len() != rows.len() - 1 - index {
+                    return Err(ParseError::new(format!(
+                        "the crate [{label}] does not sit on stack {}",
+                        stack + 1
+                    ))
+                    .on_line(index + 1));
+                }
+                stacks[stack].push(label.to_string());
+            }
+        }
+        Ok(Table { stacks })
+    }
+
+    /// Carries out `instruction` with `crane`, leaving the table as it was if the stack to take
+    /// from does not have enough crates.
+    pub fn apply(&mut self, instruction: &Instruction, crane: &impl Crane) -> ParseResult<()> {
+        let &Instruction {
+            count, from, to, ..
+        } = instruction;
+        let stack = |n: usize| {
+            n.checked_sub(1)
+                .filter(|&i| i < self.stacks.len())
+                .ok_or_else(|| ParseError::new(format!("there is no stack {n}")))
+        };
+        let (from, to) = (stack(from)?, stack(to)?);
+        let height = self.stacks[from].len();
+        let Some(rest) = height.checked_sub(count) else {
+            return Err(ParseError::new(format!(
+                "cannot take {count} crates from stack {} of {height}",
+                from + 1
+            ))
+            .on_line(instruction.line));
+        };
+        // Whatever the crane, crates put back where they were taken from are as they were.
+        if from == to {
+            return Ok(());
+        }
+        let mut crates = self.stacks[from].split_off(rest);
+        crane.arrange(&mut crates);
+        self.stacks[to].extend(crates);
+        Ok(())
+    }
+
+    /// The top crate of every stack that has one.
+    pub fn tops(&self) -> String {
+        self.stacks
+            .iter()
+            .filter_map(|stack| stack.last().map(String::as_str))
+            .collect()
+    }
+}
+
+/// The words of `line`, with the column each starts at.
+fn words(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace().map(move |word| {
+        let offset = word.as_ptr() as usize - line.as_ptr() as usize;
+        (line[..offset].chars().count(), word)
+    })
+}
+
+/// Draws the stacks the way the puzzle does, widening a stack's column to fit its widest crate.
+/// [`Table::parse`] reads the drawing back to the same table.
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths: Vec<usize> = self
+            .stacks
+            .iter()
+            .enumerate()
+            .map(|(i, stack)| {
+                // Never narrower than a one-letter crate, even when empty.
+                let number = (i + 1).to_string().len();
+                let widest = stack.iter().map(|c| c.chars().count() + 2).max();
+                widest.unwrap_or(0).max(number).max(3)
+            })
+            .collect();
+        let row = |cell: &dyn Fn(usize) -> String| {
+            let mut row = String::new();
+            for (i, width) in widths.iter().enumerate() {
+                if i > 0 {
+                    row.push(' ');
+                }
+                write!(row, "{:^width$}", cell(i)).unwrap();
+            }
+            row.trim_end().to_string()
+        };
+        let height = self.stacks.iter().map(Vec::len).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let line = row(&|i| {
+                self.stacks[i]
+                    .get(level)
+                    .map(|c| format!("[{c}]"))
+                    .unwrap_or_default()
+            });
+            writeln!(f, "{line}")?;
+        }
+        write!(f, "{}", row(&|i| (i + 1).to_string()))
+    }
+}
+
+fn parse_instruction(line_no: usize, line: &str, stacks: usize) -> ParseResult<Instruction> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    let [_, count, _, from, _, to] = parts[..] else {
+        return Err(ParseError::new(format!("expected a move, found {line:?}")));
+    };
+    let stack = |s: &str| match parse::number(s)? {
+        n @ 1.. if n <= stacks => Ok(n),
+        _ => Err(ParseError::new(format!("there is no stack {s}"))),
+    };
+    Ok(Instruction {
+        count: parse::number(count)?,
+        from: stack(from)?,
+        to: stack(to)?,
+        line: line_no,
+    })
+}
+
+pub const INPUT: &str = "inputs/2022/day05.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+pub(crate) fn parse_input(contents: &str) -> ParseResult<(Table, Vec<Instruction>)> {
+    let paragraphs = input::paragraphs(contents);
+    let (Some(table), instructions) = (paragraphs.first(), paragraphs.get(1).copied()) else {
+        return Err(ParseError::new("expected a drawing of the stacks"));
+    };
+    // The paragraphs are slices of the input, so this is how many lines come before each.
+    let skipped = |paragraph: &str| {
+        let offset = paragraph.as_ptr() as usize - contents.as_ptr() as usize;
+        contents[..offset].lines().count()
+    };
+    let table = Table::parse(table).map_err(|e| ParseError {
+        line: e.line.map(|line| line + skipped(table)),
+        ..e
+    })?;
+    let Some(instructions) = instructions else {
+        return Ok((table, vec![]));
+    };
+    let skipped = skipped(instructions);
+    let instructions = parse::numbered_lines(instructions, |line_no, line| {
+        parse_instruction(line_no + skipped, line, table.stacks.len())
+    })
+    .map_err(|e| ParseError {
+        line: e.line.map(|line| line + skipped),
+        ..e
+    })?;
+    Ok((table, instructions))
+}
+
+/// Carries out the instructions in order and tells which crates end up on top.
+pub fn interpret(
+    mut table: Table,
+    instructions: &[Instruction],
+    crane: &impl Crane,
+) -> ParseResult<String> {
+    for instruction in instructions {
+        table.apply(instruction, crane)?;
+    }
+    Ok(table.tops())
+}
+
+/// The table after each instruction, up to the first one that cannot be carried out.
+pub fn replay<'a>(
+    table: Table,
+    instructions: &'a [Instruction],
+    crane: &'a impl Crane,
+) -> impl Iterator<Item = ParseResult<Table>> + 'a {
+    let mut table = Some(table);
+    instructions.iter().map_while(move |instruction| {
+        let current = table.as_mut()?;
+        match current.apply(instruction, crane) {
+            Ok(()) => Some(Ok(current.clone())),
+            Err(e) => {
+                table = None;
+                Some(Err(e))
+            }
+        }
+    })
+}
+
+/// The stacks before and after the rearrangement by either crane, as shown by `inspect`.
+pub fn inspect(input: &str) -> Inspection {
+    let mut inspection = Inspection::default();
+    let (table, instructions) = match parse_input(input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            inspection.warn(format!("the input does not parse: {e}"));
+            return inspection;
+        }
+    };
+    let crates: usize = table.stacks.iter().map(Vec::len).sum();
+    inspection.fact("stacks", table.stacks.len());
+    inspection.fact("crates", crates);
+    inspection.fact("instructions", instructions.len());
+    inspection.fact("before", &table);
+    for (name, result) in [
+        (
+            "after 9000",
+            replay(table.clone(), &instructions, &CrateMover9000).last(),
+        ),
+        (
+            "after 9001",
+            replay(table.clone(), &instructions, &CrateMover9001).last(),
+        ),
+    ] {
+        match result.unwrap_or(Ok(table.clone())) {
+            Ok(after) => inspection.fact(name, after),
+            Err(e) => inspection.warn(format!("{name}: {e}")),
+        }
+    }
+    inspection
+}
+
+/// Whether the top crates of the stacks that have any are `target`, in order.
+fn has_tops(table: &Table, target: &[Crate]) -> bool {
+    table
+        .stacks
+        .iter()
+        .filter_map(|stack| stack.last())
+        .eq(target)
+}
+
+/// Finds the fewest instructions after which `crane` leaves `target` on top of the stacks, by
+/// breadth-first search over the tables they lead to. Gives up once `limit` tables are seen.
+/// The planned instructions are numbered as lines from 1. An empty target needs no
+/// instructions.
+pub fn plan(
+    table: &Table,
+    target: &[Crate],
+    crane: &impl Crane,
+    limit: usize,
+) -> ParseResult<Vec<Instruction>> {
+    if target.is_empty() {
+        return Ok(vec![]);
+    }
+    let wanted = target.join(", ");
+    if target.len() > table.stacks.len() {
+        return Err(ParseError::new(format!(
+            "{} stacks cannot have {wanted} on top",
+            table.stacks.len()
+        )));
+    }
+
+    // Every table reached, with the one it was reached from and how.
+    let mut reached: Vec<(Table, Option<(usize, Instruction)>)> = vec![(table.clone(), None)];
+    let mut seen = HashSet::from([table.clone()]);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        if has_tops(&reached[index].0, target) {
+            let mut instructions = vec![];
+            let mut current = index;
+            while let Some((previous, instruction)) = reached[current].1 {
+                instructions.push(instruction);
+                current = previous;
+            }
+            instructions.reverse();
+            for (i, instruction) in instructions.iter_mut().enumerate() {
+                instruction.line = i + 1;
+            }
+            return Ok(instructions);
+        }
+        let stacks = reached[index].0.stacks.len();
+        for from in 1..=stacks {
+            for to in (1..=stacks).filter(|&to| to != from) {
+                for count in 1..=reached[index].0.stacks[from - 1].len() {
+                    let instruction = Instruction {
+                        count,
+                        from,
+                        to,
+                        line: 0,
+                    };
+                    let mut next = reached[index].0.clone();
+                    next.apply(&instruction, crane)?;
+                    if seen.contains(&next) {
+                        continue;
+                    }
+                    if seen.len() >= limit {
+                        return Err(ParseError::new(format!(
+                            "found no way to get {wanted} on top within {limit} tables"
+                        )));
+                    }
+                    seen.insert(next.clone());
+                    reached.push((next, Some((index, instruction))));
+                    queue.push_back(reached.len() - 1);
+                }
+            }
+        }
+    }
+    Err(ParseError::new(format!(
+        "no instructions get {wanted} on top"
+    )))
+}
+
+/// The crates on top after `crane` rearranges the stacks of `input`.
+pub fn rearrange(input: &str, crane: &impl Crane) -> ParseResult<String> {
+    let (table, instructions) = parse_input(input)?;
+    interpret(table, &instructions, crane)
+}
+
+pub fn part1(input: &str) -> String {
+    rearrange(input, &CrateMover9000).unwrap()
+}
+
+pub fn part2(input: &str) -> String {
+    rearrange(input, &CrateMover9001).unwrap()
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input());
+    println!("{result}");
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day05::*;
+
+    const EXAMPLE: &str = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+    #[test]
+    fn inspection() {
+        let inspect = crate::inspect::inspector(2022, 5).unwrap();
+        let inspection = inspect(EXAMPLE);
+        assert!(inspection.warnings().is_empty());
+        assert_eq!(
+            inspection.to_string(),
+            "stacks        3\n\
+             crates        6\n\
+             instructions  4\n\
+             before:\n    \
+             [D]\n\
+             [N] [C]\n\
+             [Z] [M] [P]\n \
+             1   2   3\n\
+             after 9000:\n        \
+             [Z]\n        \
+             [N]\n        \
+             [D]\n\
+             [C] [M] [P]\n \
+             1   2   3\n\
+             after 9001:\n        \
+             [D]\n        \
+             [N]\n        \
+             [Z]\n\
+             [M] [C] [P]\n \
+             1   2   3\n"
+        );
+    }
+
+    #[test]
+    fn it_works() {
+        assert_eq!(part1(EXAMPLE), "CMZ");
+        assert_eq!(part2(EXAMPLE), "MCD");
+
+        // Two crates at a time, the top two first.
+        let pairs = |crates: &mut [Crate]| {
+            let landed: Vec<Crate> = crates.rchunks(2).flatten().cloned().collect();
+            crates.clone_from_slice(&landed);
+        };
+        let mut crates = ["a", "b", "c", "d", "e"].map(String::from);
+        pairs.arrange(&mut crates);
+        assert_eq!(crates, ["d", "e", "b", "c", "a"]);
+        assert_eq!(rearrange(EXAMPLE, &pairs).unwrap(), "MCZ");
+
+        let two_stacks = "[A]\n[B] [C]\n 1   2\n\nmove 2 from 1 to 2\nmove 3 from 2 to 1\n";
+        assert_eq!(rearrange(two_stacks, &CrateMover9001).unwrap(), "A");
+        assert_eq!(rearrange(two_stacks, &CrateMover9000).unwrap(), "C");
+        let in_place = "[A]\n[B]\n[C]\n 1\n\nmove 2 from 1 to 1\n";
+        assert_eq!(rearrange(in_place, &CrateMover9000).unwrap(), "A");
+        assert_eq!(rearrange(in_place, &CrateMover9001).unwrap(), "A");
+        assert_eq!(rearrange(in_place, &pairs).unwrap(), "A");
+        assert!(rearrange(&in_place.replace('2', "4"), &CrateMover9000).is_err());
+        let underflow = "[A]\n 1   2\n\nmove 2 from 1 to 2\n";
+        assert_eq!(
+            rearrange(underflow, &CrateMover9000)
+                .unwrap_err()
+                .to_string(),
+            "line 4: cannot take 2 crates from stack 1 of 1"
+        );
+    }
+
+    #[test]
+    fn drawings() {
+        let (table, instructions) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(
+            table.to_string(),
+            "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3"
+        );
+        let after: Vec<Table> = replay(table, &instructions, &CrateMover9000)
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(
+            after[1].to_string(),
+            "        [Z]\n        [N]\n    [C] [D]\n    [M] [P]\n 1   2   3"
+        );
+        assert_eq!(Table::parse(&after[1].to_string()), Ok(after[1].clone()));
+
+        let wide = "   [Fe]\n[Cu] [Au]  [Pb]\n 1    2     3";
+        let table = Table::parse(wide).unwrap();
+        assert_eq!(table.tops(), "CuFePb");
+        let drawn = table.to_string();
+        assert_eq!(drawn, "     [Fe]\n[Cu] [Au] [Pb]\n 1    2    3");
+        assert_eq!(Table::parse(&drawn), Ok(table));
+
+        assert_eq!(
+            Table::parse("[A]\n    [B]\n 1   2")
+                .unwrap_err()
+                .to_string(),
+            "line 1: the crate [A] does not sit on stack 1"
+        );
+        assert_eq!(
+            Table::parse("[A] B\n 1   2").unwrap_err().to_string(),
+            "line 1: expected a crate like [A], found \"B\""
+        );
+        assert!(Table::parse("[A]\n 2").is_err());
+
+        for label in ["A B", "", "[A", "A]", "A\tB"] {
+            assert_eq!(
+                Table::new(vec![vec![label.to_string()], vec![]])
+                    .unwrap_err()
+                    .to_string(),
+                format!("a crate cannot be labelled {label:?}")
+            );
+        }
+        let labels = vec![vec!["Fe".to_string(), "é".to_string()], vec![]];
+        let table = Table::new(labels).unwrap();
+        assert_eq!(Table::parse(&table.to_string()), Ok(table));
+    }
+
+    #[test]
+    fn planning() {
+        let (table, _) = parse_input(EXAMPLE).unwrap();
+        let target = ["C", "M", "Z"].map(String::from);
+        let instructions = plan(&table, &target, &CrateMover9000, 100_000).unwrap();
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(instructions[0].to_string(), "move 2 from 1 to 3");
+        let text: Vec<String> = instructions.iter().map(|i| i.to_string()).collect();
+        let input = format!("{table}\n\n{}\n", text.join("\n"));
+        assert_eq!(rearrange(&input, &CrateMover9000).unwrap(), "CMZ");
+
+        let unchanged = plan(
+            &table,
+            &["N", "D", "P"].map(String::from),
+            &CrateMover9001,
+            10,
+        );
+        assert_eq!(unchanged, Ok(vec![]));
+        assert_eq!(
+            plan(&table, &["X"].map(String::from), &CrateMover9000, 1_000)
+                .unwrap_err()
+                .to_string(),
+            "found no way to get X on top within 1000 tables"
+        );
+        assert_eq!(plan(&table, &[], &CrateMover9000, 0), Ok(vec![]));
+        assert_eq!(
+            plan(&table, &target, &CrateMover9000, 0)
+                .unwrap_err()
+                .to_string(),
+            "found no way to get C, M, Z on top within 0 tables"
+        );
+        let two = Table::new(vec![vec!["A".to_string()], vec!["B".to_string()]]).unwrap();
+        assert_eq!(
+            plan(&two, &["X"].map(String::from), &CrateMover9000, 1_000)
+                .unwrap_err()
+                .to_string(),
+            "no instructions get X on top"
+        );
+        assert!(plan(
+            &two,
+            &["A", "B", "C"].map(String::from),
+            &CrateMover9000,
+            1_000
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::VecDeque;
+use std::io::{BufRead, Bytes};
+
+use crate::input;
+use crate::parse::{ParseError, ParseResult};
+
+pub const INPUT: &str = "inputs/2022/day06.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+/// Finds markers, runs of `size` different bytes, in a stream fed to it one byte at a time.
+/// Any byte may occur, and each byte takes the same time whatever the size.
+pub struct Detector {
+    size: usize,
+    window: VecDeque<u8>,
+    /// How often each byte occurs in the window.
+    counts: [usize; 256],
+    /// How many different bytes the window holds.
+    distinct: usize,
+    position: usize,
+}
+
+impl Detector {
+    /// Fails for a size of 0, as a marker needs at least one byte.
+    pub fn new(size: usize) -> ParseResult<Self> {
+        if size == 0 {
+            return Err(ParseError::new("a marker needs at least one byte"));
+        }
+        Ok(Detector {
+            size,
+            window: VecDeque::with_capacity(size + 1),
+            counts: [0; 256],
+            distinct: 0,
+            position: 0,
+        })
+    }
+
+    /// Takes the next byte, telling whether it ends a marker.
+    pub fn push(&mut self, byte: u8) -> bool {
+        self.position += 1;
+        self.window.push_back(byte);
+        self.counts[byte as usize] += 1;
+        if self.counts[byte as usize] == 1 {
+            self.distinct += 1;
+        }
+        if self.window.len() > self.size {
+            let old = self.window.pop_front().unwrap() as usize;
+            self.counts[old] -= 1;
+            if self.counts[old] == 0 {
+                self.distinct -= 1;
+            }
+        }
+        self.window.len() == self.size && self.distinct == self.size
+    }
+
+    /// How many bytes were pushed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+/// The markers of a stream, as the number of bytes read up to the end of each.
+pub struct Markers<R> {
+    bytes: Bytes<R>,
+    detector: Detector,
+}
+
+/// Every marker of `size` different bytes in what `reader` reads. Markers may overlap.
+pub fn markers<R: BufRead>(reader: R, size: usize) -> ParseResult<Markers<R>> {
+    Ok(Markers {
+        bytes: reader.bytes(),
+        detector: Detector::new(size)?,
+    })
+}
+
+impl<R: BufRead> Iterator for Markers<R> {
+    type Item = ParseResult<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for byte in self.bytes.by_ref() {
+            match byte {
+                Ok(byte) if self.detector.push(byte) => return Some(Ok(self.detector.position())),
+                Ok(_) => {}
+                Err(e) => {
+                    return Some(Err(ParseError::new(format!(
+                        "could not read the input: {e}"
+                    ))))
+                }
+            }
+        }
+        None
+    }
+}
+
+pub(crate) fn parse_input(input: &str) -> ParseResult<&[u8]> {
+    let datastream = input.trim();
+    if datastream.contains('\n') {
+        return Err(ParseError::new("expected the datastream on one line"));
+    }
+    Ok(datastream.as_bytes())
+}
+
+fn solve(input: &str, window_size: usize) -> usize {
+    let datastream = parse_input(input).unwrap();
+    match markers(datastream, window_size).unwrap().next() {
+        Some(marker) => marker.unwrap(),
+        None => 0,
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    solve(input, 4)
+}
+
+pub fn part2(input: &str) -> usize {
+    solve(input, 14)
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input());
+    println!("{result}");
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day06::*;
+
+    fn all(stream: &[u8], size: usize) -> Vec<usize> {
+        markers(stream, size).unwrap().map(Result::unwrap).collect()
+    }
+
+    #[test]
+    fn it_works() {
+        assert_eq!(all(b"abcd", 4), vec![4]);
+        assert_eq!(all(b"abad", 4), vec![]);
+        assert_eq!(part1("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n"), 7);
+        assert_eq!(part2("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n"), 19);
+        assert_eq!(all(b"aabcabb", 3), vec![4, 5, 6]);
+        assert_eq!(all("A1\u{e9}\n\0".as_bytes(), 5), vec![5, 6]);
+        assert_eq!(all(b"xyz", 1), vec![1, 2, 3]);
+        assert_eq!(
+            markers(&b"abc"[..], 0).err().unwrap().to_string(),
+            "a marker needs at least one byte"
+        );
+        assert!(Detector::new(0).is_err());
+    }
+}
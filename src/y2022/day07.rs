@@ -1,10 +1,12 @@
 use std::collections::HashMap;
-use std::fs;
+
+use crate::input;
+use crate::parse::{self, ParseError, ParseResult};
 
 type Path = Vec<String>;
 
 #[derive(Debug)]
-enum FileOrDir {
+pub(crate) enum FileOrDir {
     File(i32),
     Dir(String),
 }
@@ -12,15 +14,19 @@ enum FileOrDir {
 type FS = HashMap<Path, Vec<FileOrDir>>;
 
 #[derive(Debug)]
-enum Instruction {
+pub(crate) enum Instruction {
     CdUp,
     CdDown(String),
     Ls(Vec<FileOrDir>),
 }
 
-fn read_input() -> Vec<Instruction> {
-    let file_path = "inputs/day07.txt";
-    let input = fs::read_to_string(file_path).expect("Should have been able to read the file");
+pub const INPUT: &str = "inputs/2022/day07.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+pub(crate) fn parse_input(input: &str) -> ParseResult<Vec<Instruction>> {
     let mut cmds = input.split('$');
     let mut instructions = vec![];
     cmds.next();
@@ -32,23 +38,26 @@ fn read_input() -> Vec<Instruction> {
             } else {
                 instructions.push(Instruction::CdDown(dir.to_string()));
             }
-        } else if let Some(listing) = cmd.strip_prefix("ls\n") {
+        } else if let Some(listing) = cmd
+            .strip_prefix("ls")
+            .filter(|l| l.is_empty() || l.starts_with('\n'))
+        {
             let mut entries = vec![];
-            for line in listing.lines() {
-                let (lhs, rhs) = line.split_once(' ').unwrap();
+            for line in listing.lines().skip_while(|l| l.is_empty()) {
+                let (lhs, rhs) = parse::split_once(line, " ")?;
                 if lhs == "dir" {
                     entries.push(FileOrDir::Dir(rhs.to_string()));
                 } else {
-                    let size = lhs.parse().unwrap();
+                    let size = parse::number(lhs)?;
                     entries.push(FileOrDir::File(size));
                 }
             }
             instructions.push(Instruction::Ls(entries));
         } else {
-            panic!("invalid command {}", cmd);
+            return Err(ParseError::new(format!("invalid command {cmd:?}")));
         }
     }
-    instructions
+    Ok(instructions)
 }
 
 fn make_fs(instructions: Vec<Instruction>) -> FS {
@@ -88,8 +97,8 @@ fn size_of_dir(fs: &FS, dir: &Path) -> i32 {
     acc
 }
 
-pub fn solve1() {
-    let instructions = read_input();
+pub fn part1(input: &str) -> i32 {
+    let instructions = parse_input(input).unwrap();
     let fs = make_fs(instructions);
     let mut result = 0;
     for path in fs.keys() {
@@ -98,11 +107,11 @@ pub fn solve1() {
             result += size;
         }
     }
-    println!("{result}")
+    result
 }
 
-pub fn solve2() {
-    let instructions = read_input();
+pub fn part2(input: &str) -> i32 {
+    let instructions = parse_input(input).unwrap();
     let fs = make_fs(instructions);
     let total_space = 70_000_000;
     let required_space = 30_000_000;
@@ -116,5 +125,15 @@ pub fn solve2() {
             result = size;
         }
     }
+    result
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}")
+}
+
+pub fn solve2() {
+    let result = part2(&read_input());
     println!("{result}")
 }
@@ -1,21 +1,21 @@
-use std::fs;
+use crate::input;
+use crate::parse::{self, ParseError, ParseResult};
 
-fn read_input() -> Vec<Vec<i32>> {
-    let file_path = "inputs/day08.txt";
-    let input = fs::read_to_string(file_path).expect("Should have been able to read the file");
-    parse_input(input)
+pub const INPUT: &str = "inputs/2022/day08.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
 }
 
-fn parse_input(input: String) -> Vec<Vec<i32>> {
-    input
-        .trim()
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_string().parse().unwrap())
-                .collect()
-        })
-        .collect()
+pub(crate) fn parse_input(input: &str) -> ParseResult<Vec<Vec<i32>>> {
+    parse::lines(input, |line| {
+        line.chars()
+            .map(|c| match c.to_digit(10) {
+                Some(height) => Ok(height as i32),
+                None => Err(ParseError::new(format!("expected a digit, found {c:?}"))),
+            })
+            .collect()
+    })
 }
 
 struct Directions<'a> {
@@ -104,27 +104,33 @@ fn find_scenic_score(value: i32, iter: &mut &mut dyn Iterator<Item = i32>) -> i3
     res
 }
 
+pub fn part1(input: &str) -> i32 {
+    count_visible_trees(parse_input(input).unwrap())
+}
+
+pub fn part2(input: &str) -> i32 {
+    find_best_scenic_score(parse_input(input).unwrap())
+}
+
 pub fn solve1() {
-    let input = read_input();
-    let result = count_visible_trees(input);
+    let result = part1(&read_input());
     println!("{result}")
 }
 
 pub fn solve2() {
-    let input = read_input();
-    let result = find_best_scenic_score(input);
+    let result = part2(&read_input());
     println!("{result}")
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day08::count_visible_trees;
-    use crate::day08::parse_input;
+    use crate::y2022::day08::count_visible_trees;
+    use crate::y2022::day08::parse_input;
 
     #[test]
     fn it_works() {
         let input = "30373\n25512\n65332\n33549\n35390";
-        let grid = parse_input(input.to_string());
+        let grid = parse_input(input).unwrap();
         /*println!("{grid:#?}");*/
         let result = count_visible_trees(grid);
         println!("{result}");
@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+
+use crate::input;
+use crate::parse::{self, ParseError, ParseResult};
+use crate::simulation::{self, Simulation};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub fn parse(str: &str) -> ParseResult<Self> {
+        match str {
+            "D" => Ok(Direction::Down),
+            "U" => Ok(Direction::Up),
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(ParseError::new(format!("unknown direction {str:?}"))),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Default, Hash, Clone)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+impl Position {
+    pub fn step(&mut self, direction: Direction) {
+        match direction {
+            Direction::Up => self.y += 1,
+            Direction::Down => self.y -= 1,
+            Direction::Left => self.x -= 1,
+            Direction::Right => self.x += 1,
+        }
+    }
+
+    pub fn drag(&self, tail: Position) -> Position {
+        let mut tail = tail;
+        let dx = self.x - tail.x;
+        let dy = self.y - tail.y;
+        if dx.abs() > 1 || dy.abs() > 1 {
+            tail.x += dx.signum();
+            tail.y += dy.signum();
+        }
+        tail
+    }
+}
+
+pub const INPUT: &str = "inputs/2022/day09.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+/// The moves as a direction and how many steps to take in it.
+pub(crate) fn parse_input(input: &str) -> ParseResult<Vec<(Direction, usize)>> {
+    parse::lines(input, |line| {
+        let (direction, count) = parse::split_once(line, " ")?;
+        Ok((Direction::parse(direction)?, parse::number(count)?))
+    })
+}
+
+/// A rope of knots whose head follows the moves one at a time, remembering where its tail went.
+struct Rope {
+    knots: Vec<Position>,
+    moves: Vec<Direction>,
+    next_move: usize,
+    tail_visited: HashSet<Position>,
+}
+
+impl Rope {
+    fn new(knots: usize, moves: Vec<(Direction, usize)>) -> Self {
+        let moves = moves
+            .into_iter()
+            .flat_map(|(direction, count)| std::iter::repeat_n(direction, count))
+            .collect();
+        let knots = vec![Position::default(); knots];
+        let tail_visited = HashSet::from([knots[knots.len() - 1].clone()]);
+        Rope {
+            knots,
+            moves,
+            next_move: 0,
+            tail_visited,
+        }
+    }
+}
+
+impl Simulation for Rope {
+    type Snapshot = Vec<Position>;
+
+    fn step(&mut self) {
+        self.knots[0].step(self.moves[self.next_move]);
+        self.next_move += 1;
+        for i in 0..self.knots.len() - 1 {
+            self.knots[i + 1] = self.knots[i].drag(self.knots[i + 1].clone());
+        }
+        self.tail_visited
+            .insert(self.knots[self.knots.len() - 1].clone());
+    }
+
+    fn is_done(&self) -> bool {
+        self.next_move == self.moves.len()
+    }
+
+    fn snapshot(&self) -> Vec<Position> {
+        self.knots.clone()
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    let mut rope = Rope::new(2, parse_input(input).unwrap());
+    simulation::run(&mut rope);
+    rope.tail_visited.len()
+}
+
+pub fn part2(input: &str) -> usize {
+    let mut rope = Rope::new(10, parse_input(input).unwrap());
+    simulation::run(&mut rope);
+    rope.tail_visited.len()
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input());
+    println!("{result}");
+}
@@ -0,0 +1,107 @@
+use crate::input;
+use crate::parse::{self, ParseResult};
+use crate::simulation::Simulation;
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Instruction {
+    Noop,
+    Add(i32),
+}
+
+pub const INPUT: &str = "inputs/2022/day10.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+pub(crate) fn parse_input(input: &str) -> ParseResult<Vec<Instruction>> {
+    let lines = parse::lines(input, |line| {
+        if line == "noop" {
+            Ok(vec![Instruction::Noop])
+        } else {
+            let count = parse::number(parse::strip_prefix(line, "addx ")?)?;
+            Ok(vec![Instruction::Noop, Instruction::Add(count)])
+        }
+    })?;
+    Ok(lines.into_iter().flatten().collect())
+}
+
+/// The CPU executes one instruction per cycle, `addx` having been split into a `noop` and an add.
+struct Cpu {
+    instructions: Vec<Instruction>,
+    cycle: usize,
+    reg_x: i32,
+}
+
+impl Cpu {
+    fn new(instructions: Vec<Instruction>) -> Self {
+        Cpu {
+            instructions,
+            cycle: 0,
+            reg_x: 1,
+        }
+    }
+}
+
+impl Simulation for Cpu {
+    type Snapshot = (usize, i32);
+
+    fn step(&mut self) {
+        match self.instructions[self.cycle] {
+            Instruction::Noop => {}
+            Instruction::Add(v) => {
+                self.reg_x += v;
+            }
+        }
+        self.cycle += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.cycle == self.instructions.len()
+    }
+
+    fn snapshot(&self) -> (usize, i32) {
+        (self.cycle, self.reg_x)
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    let mut cpu = Cpu::new(parse_input(input).unwrap());
+    let mut result: i32 = 0;
+    while !cpu.is_done() {
+        let i = cpu.cycle + 1;
+        if (i + 20).is_multiple_of(40) {
+            result += i as i32 * cpu.reg_x;
+        }
+        cpu.step();
+    }
+    result
+}
+
+pub fn part2(input: &str) -> String {
+    let mut cpu = Cpu::new(parse_input(input).unwrap());
+    let mut screen = String::new();
+    while !cpu.is_done() {
+        let electron_x = cpu.cycle as i32 % 40;
+        if electron_x == 0 && cpu.cycle > 0 {
+            screen.push('\n')
+        }
+        if (electron_x - cpu.reg_x).abs() < 2 {
+            screen.push('#')
+        } else {
+            screen.push('.')
+        }
+        cpu.step();
+    }
+    screen
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input());
+    println!("{result}");
+}
@@ -0,0 +1,222 @@
+use nom::{
+    branch::alt, bytes::complete::tag, character::complete as text, combinator::map, multi,
+    sequence::tuple, IResult,
+};
+use std::mem;
+
+use crate::control::{Cancelled, Control};
+use crate::events::Event;
+use crate::input;
+use crate::parse::{ParseError, ParseResult};
+use crate::simulation::{self, Simulation};
+
+#[derive(Debug)]
+enum Op {
+    Add,
+    Multiply,
+}
+
+#[derive(Debug)]
+pub(crate) struct Monkey {
+    pub items: Vec<u64>,
+    test: (u64, usize, usize),
+    operation: (Op, Option<u64>),
+    inspected_items: u64,
+}
+
+impl Monkey {
+    fn inspect(&mut self, worry_level: u64) -> u64 {
+        self.inspected_items += 1;
+        let ring = 2 * 3 * 5 * 7 * 11 * 13 * 17 * 19;
+        let operand = self.operation.1.unwrap_or(worry_level);
+        let worry_level = worry_level % ring;
+        let operand = operand % ring;
+        match self.operation.0 {
+            Op::Add => worry_level + operand,
+            Op::Multiply => worry_level * operand,
+        }
+    }
+
+    fn throw(&self, worry_level: u64) -> usize {
+        if worry_level.is_multiple_of(self.test.0) {
+            self.test.1
+        } else {
+            self.test.2
+        }
+    }
+}
+
+pub const INPUT: &str = "inputs/2022/day11.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+fn parse_items(input: &str) -> IResult<&str, Vec<u64>> {
+    let (input, _) = tuple((text::multispace1, tag("Starting items: ")))(input)?;
+    multi::separated_list0(tag(", "), text::u64)(input)
+}
+
+fn parse_operation(input: &str) -> IResult<&str, (Op, Option<u64>)> {
+    let (input, _) = tuple((text::multispace1, tag("Operation: new = old ")))(input)?;
+    let (input, operation) =
+        alt((map(tag("+"), |_| Op::Add), map(tag("*"), |_| Op::Multiply)))(input)?;
+    let (input, _) = text::multispace1(input)?;
+    let (input, operand) = alt((map(tag("old"), |_| None), map(text::u64, Some)))(input)?;
+    Ok((input, (operation, operand)))
+}
+
+fn parse_test(input: &str) -> IResult<&str, (u64, usize, usize)> {
+    let (input, _) = tuple((text::multispace1, tag("Test: divisible by ")))(input)?;
+    let (input, div) = text::u64(input)?;
+    let (input, _) = text::newline(input)?;
+    let (input, _) = tuple((text::multispace1, tag("If true: throw to monkey ")))(input)?;
+    let (input, then_monkey) = text::u64(input)?;
+    let (input, _) = text::newline(input)?;
+    let (input, _) = tuple((text::multispace1, tag("If false: throw to monkey ")))(input)?;
+    let (input, else_monkey) = text::u64(input)?;
+    Ok((input, (div, then_monkey as usize, else_monkey as usize)))
+}
+
+fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
+    let (input, _) = tuple((
+        tag("Monkey"),
+        text::multispace1,
+        text::u64,
+        tag(":"),
+        text::newline,
+    ))(input)?;
+    let (input, items) = parse_items(input)?;
+    let (input, _) = text::newline(input)?;
+    let (input, operation) = parse_operation(input)?;
+    let (input, _) = text::newline(input)?;
+    let (input, test) = parse_test(input)?;
+    Ok((
+        input,
+        Monkey {
+            items,
+            operation,
+            test,
+            inspected_items: 0,
+        },
+    ))
+}
+
+fn parse_monkeys(input: &str) -> IResult<&str, Vec<Monkey>> {
+    multi::separated_list0(text::multispace1, parse_monkey)(input)
+}
+
+pub(crate) fn parse_input(input: &str) -> ParseResult<Vec<Monkey>> {
+    // The line of the text nom stopped at, counting from 1.
+    let line_at = |rest: &str| input[..input.len() - rest.len()].lines().count().max(1);
+    let (rest, monkeys) = match parse_monkeys(input) {
+        Ok(parsed) => parsed,
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            return Err(ParseError::new("expected a monkey").on_line(line_at(e.input)))
+        }
+        Err(nom::Err::Incomplete(_)) => return Err(ParseError::new("unexpected end of input")),
+    };
+    if !rest.trim().is_empty() {
+        return Err(ParseError::new("expected a monkey").on_line(line_at(rest)));
+    }
+    for monkey in &monkeys {
+        let (_, then_monkey, else_monkey) = monkey.test;
+        if then_monkey.max(else_monkey) >= monkeys.len() {
+            return Err(ParseError::new(
+                "a monkey throws to a monkey that does not exist",
+            ));
+        }
+    }
+    Ok(monkeys)
+}
+
+/// The monkeys playing keep-away, one step being one round of every monkey taking its turn.
+struct KeepAway {
+    monkeys: Vec<Monkey>,
+    /// Whether worry levels are divided by three after each inspection.
+    relief: bool,
+    rounds: usize,
+    /// The items thrown in the last round: from which monkey to which, and their worry level.
+    throws: Vec<(usize, usize, u64)>,
+}
+
+impl KeepAway {
+    fn new(monkeys: Vec<Monkey>, relief: bool) -> Self {
+        KeepAway {
+            monkeys,
+            relief,
+            rounds: 0,
+            throws: vec![],
+        }
+    }
+
+    fn monkey_business(mut self) -> u64 {
+        let monkeys = &mut self.monkeys;
+        monkeys.sort_by_key(|m| m.inspected_items);
+        monkeys[monkeys.len() - 1].inspected_items * monkeys[monkeys.len() - 2].inspected_items
+    }
+}
+
+impl Simulation for KeepAway {
+    /// The items every monkey holds.
+    type Snapshot = Vec<Vec<u64>>;
+
+    fn step(&mut self) {
+        let monkeys = &mut self.monkeys;
+        self.rounds += 1;
+        self.throws.clear();
+        for monkey_index in 0..monkeys.len() {
+            let items = mem::take(&mut monkeys[monkey_index].items);
+            for item in items {
+                let mut new_item = monkeys[monkey_index].inspect(item);
+                if self.relief {
+                    new_item /= 3;
+                }
+                let next_monkey_index = monkeys[monkey_index].throw(new_item);
+                monkeys[next_monkey_index].items.push(new_item);
+                self.throws
+                    .push((monkey_index, next_monkey_index, new_item));
+            }
+        }
+    }
+
+    fn snapshot(&self) -> Vec<Vec<u64>> {
+        self.monkeys.iter().map(|m| m.items.clone()).collect()
+    }
+
+    fn events(&self) -> Vec<Event> {
+        self.throws
+            .iter()
+            .map(|&(from, to, worry)| {
+                Event::new("item_thrown")
+                    .with("round", self.rounds)
+                    .with("from", from)
+                    .with("to", to)
+                    .with("worry", worry)
+            })
+            .collect()
+    }
+}
+
+pub fn part1(input: &str, control: &Control) -> Result<u64, Cancelled> {
+    let mut game = KeepAway::new(parse_input(input).unwrap(), true);
+    simulation::run_controlled(&mut game, Some(20), control, "rounds")?;
+    Ok(game.monkey_business())
+}
+
+// https://de.wikipedia.org/wiki/Restklassenring
+pub fn part2(input: &str, control: &Control) -> Result<u64, Cancelled> {
+    let mut game = KeepAway::new(parse_input(input).unwrap(), false);
+    simulation::run_controlled(&mut game, Some(10000), control, "rounds")?;
+    Ok(game.monkey_business())
+}
+
+pub fn solve1() {
+    let result = part1(&read_input(), &Control::default()).unwrap();
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input(), &Control::default()).unwrap();
+    println!("{result}");
+}
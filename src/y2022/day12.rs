@@ -1,9 +1,11 @@
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap};
-use std::fs;
+
+use crate::input;
+use crate::parse::{ParseError, ParseResult};
 
 #[derive(Debug)]
-struct Graph {
+pub(crate) struct Graph {
     vertices: Vec<Vertex>,
 }
 
@@ -58,29 +60,32 @@ impl Graph {
     }
 }
 
-fn read_input() -> (Graph, Vec<Vec<i32>>, usize, usize) {
-    let file_path = "inputs/day12.txt";
-    let input = fs::read_to_string(file_path).expect("Should have been able to read the file");
-    parse_input(input)
+pub const INPUT: &str = "inputs/2022/day12.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
 }
 
-fn char_to_height(c: char) -> i32 {
-    if c == 'S' {
-        return 0;
-    }
-    if c == 'E' {
-        return 25;
+fn char_to_height(c: char) -> ParseResult<i32> {
+    match c {
+        'S' => Ok(0),
+        'E' => Ok(25),
+        'a'..='z' => Ok(c as i32 - 97),
+        _ => Err(ParseError::new(format!("unexpected height {c:?}"))),
     }
-    c as i32 - 97
 }
 
-fn parse_input(input: String) -> (Graph, Vec<Vec<i32>>, usize, usize) {
+pub(crate) fn parse_input(input: &str) -> ParseResult<(Graph, Vec<Vec<i32>>, usize, usize)> {
     let mut grid: Vec<Vec<i32>> = vec![];
     let mut start = None;
     let mut end = None;
 
-    for row in input.lines() {
-        let row_len = row.len();
+    let rows = input.lines().enumerate();
+    for (index, row) in rows.filter(|(_, row)| !row.trim().is_empty()) {
+        let row_len = row.chars().count();
+        if grid.first().is_some_and(|first| first.len() != row_len) {
+            return Err(ParseError::new("rows differ in length").on_line(index + 1));
+        }
         let mut new_row = vec![];
         for (column, height) in row.chars().enumerate() {
             if height == 'S' {
@@ -89,13 +94,13 @@ fn parse_input(input: String) -> (Graph, Vec<Vec<i32>>, usize, usize) {
             if height == 'E' {
                 end = Some(row_len * grid.len() + column);
             }
-            new_row.push(char_to_height(height));
+            new_row.push(char_to_height(height).map_err(|e| e.on_line(index + 1))?);
         }
         grid.push(new_row);
     }
 
-    let start = start.unwrap();
-    let end = end.unwrap();
+    let start = start.ok_or_else(|| ParseError::new("there is no start S"))?;
+    let end = end.ok_or_else(|| ParseError::new("there is no end E"))?;
     let row_len = grid[0].len();
 
     let mut vertices = vec![];
@@ -129,7 +134,7 @@ fn parse_input(input: String) -> (Graph, Vec<Vec<i32>>, usize, usize) {
         }
     }
 
-    (Graph { vertices }, grid, start, end)
+    Ok((Graph { vertices }, grid, start, end))
 }
 
 fn shortest_path(graph: Graph, start: usize, stop: impl Fn(usize) -> bool) -> usize {
@@ -169,28 +174,36 @@ fn shortest_path(graph: Graph, start: usize, stop: impl Fn(usize) -> bool) -> us
     }
 }
 
+pub fn part1(input: &str) -> usize {
+    let (graph, _, start, end) = parse_input(input).unwrap();
+    shortest_path(graph, start, |pos| pos == end)
+}
+
+pub fn part2(input: &str) -> usize {
+    let (graph, grid, _, end) = parse_input(input).unwrap();
+    shortest_path(graph.transpose(), end, |pos| {
+        grid[pos / grid[0].len()][pos % grid[0].len()] == 0
+    })
+}
+
 pub fn solve1() {
-    let (graph, _, start, end) = read_input();
-    let result = shortest_path(graph, start, |pos| pos == end);
+    let result = part1(&read_input());
     println!("{result}");
 }
 
 pub fn solve2() {
-    let (graph, grid, _, end) = read_input();
-    let result = shortest_path(graph.transpose(), end, |pos| {
-        grid[pos / grid[0].len()][pos % grid[0].len()] == 0
-    });
+    let result = part2(&read_input());
     println!("{result}");
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day12::parse_input;
+    use crate::y2022::day12::parse_input;
 
     #[test]
     fn it_works() {
         let input = "abcSE";
-        let (g, _, _s, _e) = parse_input(input.to_string());
+        let (g, _, _s, _e) = parse_input(input).unwrap();
         dbg!(&g);
         dbg!(g.transpose());
     }
@@ -0,0 +1,140 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use nom::character::complete::{char, u32};
+use nom::{branch::alt, multi, sequence::delimited, IResult, Parser};
+
+use crate::input;
+use crate::parse::{ParseError, ParseResult};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Tree {
+    Leaf(u32),
+    Node(Vec<Tree>),
+}
+
+impl fmt::Display for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tree::Leaf(n) => write!(f, "{n}"),
+            Tree::Node(children) => {
+                write!(f, "[")?;
+                for (i, child) in children.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{child}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+pub const INPUT: &str = "inputs/2022/day13.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+pub fn cmp_tree(t1: &Tree, t2: &Tree) -> Ordering {
+    match (t1, t2) {
+        (Tree::Leaf(l1), Tree::Leaf(l2)) => l1.cmp(l2),
+        (Tree::Node(n1), Tree::Node(n2)) => {
+            let len_cmp = n1.len().cmp(&n2.len());
+            for (l, r) in n1.iter().zip(n2) {
+                let cmp = cmp_tree(l, r);
+                if !cmp.is_eq() {
+                    return cmp;
+                }
+            }
+            len_cmp
+        }
+        (Tree::Leaf(_), _) => cmp_tree(&Tree::Node(vec![t1.clone()]), t2),
+        (_, Tree::Leaf(_)) => cmp_tree(t1, &Tree::Node(vec![t2.clone()])),
+    }
+}
+
+/// How deeply packets may nest. The parser and [`cmp_tree`] recurse once per level, so this
+/// keeps hostile inputs from overflowing the stack.
+const MAX_DEPTH: usize = 256;
+
+fn tree(input: &str) -> IResult<&str, Tree> {
+    let node = delimited(
+        char('['),
+        multi::separated_list0(char(','), tree),
+        char(']'),
+    )
+    .map(Tree::Node);
+    let leaf = u32.map(Tree::Leaf);
+    alt((node, leaf))(input)
+}
+
+/// Parses a whole packet such as `[1,[2,3]]`.
+pub fn parse_tree(packet: &str) -> ParseResult<Tree> {
+    let mut depth = 0usize;
+    for c in packet.chars() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        if depth > MAX_DEPTH {
+            return Err(ParseError::new(format!(
+                "packets may not nest deeper than {MAX_DEPTH}"
+            )));
+        }
+    }
+    match tree(packet) {
+        Ok(("", tree)) => Ok(tree),
+        _ => Err(ParseError::new(format!("invalid packet {packet:?}"))),
+    }
+}
+
+pub(crate) fn parse_input(input: &str) -> ParseResult<Vec<(Tree, Tree)>> {
+    let mut result = vec![];
+    for pair in input::paragraphs(input) {
+        let [first, second] = pair.lines().collect::<Vec<_>>()[..] else {
+            return Err(ParseError::new(format!(
+                "expected a pair of packets in {pair:?}"
+            )));
+        };
+        result.push((parse_tree(first)?, parse_tree(second)?));
+    }
+    Ok(result)
+}
+
+pub fn part1(input: &str) -> usize {
+    let trees = parse_input(input).unwrap();
+    let mut result = 0;
+    for (i, (left, right)) in trees.iter().enumerate() {
+        if cmp_tree(left, right).is_lt() {
+            result += i + 1;
+        }
+    }
+    result
+}
+
+pub fn part2(input: &str) -> usize {
+    let trees = parse_input(input).unwrap();
+    let marker1 = parse_tree("[[2]]").unwrap();
+    let marker2 = parse_tree("[[6]]").unwrap();
+    let mut trees: Vec<Tree> = trees.into_iter().flat_map(|(l, r)| vec![l, r]).collect();
+    trees.push(marker1.clone());
+    trees.push(marker2.clone());
+    trees.sort_by(cmp_tree);
+
+    let pos1 = trees.iter().position(|t| t == &marker1).unwrap() + 1;
+    let pos2 = trees.iter().position(|t| t == &marker2).unwrap() + 1;
+    pos1 * pos2
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input());
+    println!("{result}");
+}
@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+
+use crate::input;
+use crate::parse::{self, ParseError, ParseResult};
+use crate::simulation::{self, Simulation};
+
+type Coord = (i32, i32);
+
+pub const INPUT: &str = "inputs/2022/day14.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+fn parse_coord(str: &str) -> ParseResult<Coord> {
+    let (left, right) = parse::split_once(str, ",")?;
+    Ok((parse::number(left)?, parse::number(right)?))
+}
+
+/// The rock paths, each a list of the corners of horizontal and vertical lines.
+pub(crate) fn parse_input(input: &str) -> ParseResult<Vec<Vec<Coord>>> {
+    parse::lines(input, |line| {
+        let path = line
+            .split(" -> ")
+            .map(parse_coord)
+            .collect::<ParseResult<Vec<_>>>()?;
+        for pair in path.windows(2) {
+            if pair[0].0 != pair[1].0 && pair[0].1 != pair[1].1 {
+                return Err(ParseError::new("rock lines must be horizontal or vertical"));
+            }
+        }
+        Ok(path)
+    })
+}
+
+/// The rocks the paths draw, and the y of the lowest one.
+fn draw_rocks(paths: &[Vec<Coord>]) -> (HashSet<Coord>, i32) {
+    let mut coords = HashSet::new();
+    let mut abyss = 0;
+
+    for path in paths {
+        let mut current = path[0];
+        coords.insert(current);
+
+        for &next in &path[1..] {
+            if current.0 == next.0 {
+                for y in current.1.min(next.1)..=current.1.max(next.1) {
+                    abyss = abyss.max(y);
+                    coords.insert((current.0, y));
+                }
+            } else {
+                for x in current.0.min(next.0)..=current.0.max(next.0) {
+                    coords.insert((x, current.1));
+                }
+            }
+            current = next;
+        }
+    }
+    (coords, abyss)
+}
+
+/// Sand pouring into the cave, one step being one grain falling until it rests.
+struct Cave {
+    world: HashSet<Coord>,
+    abyss: i32,
+    /// Part 2 has a floor two below the lowest rock, part 1 lets sand fall into the abyss.
+    floor: Option<i32>,
+    grains: i32,
+    last_rest: Option<Coord>,
+    done: bool,
+}
+
+impl Cave {
+    fn new(input: &str, with_floor: bool) -> Self {
+        let (world, abyss) = draw_rocks(&parse_input(input).unwrap());
+        Cave {
+            world,
+            abyss,
+            floor: with_floor.then_some(abyss + 2),
+            grains: 0,
+            last_rest: None,
+            done: false,
+        }
+    }
+}
+
+impl Simulation for Cave {
+    /// How many grains came to rest and where the last one did.
+    type Snapshot = (i32, Option<Coord>);
+
+    fn step(&mut self) {
+        let world = &mut self.world;
+        let mut sand = (500, 0);
+        if world.contains(&sand) {
+            self.done = true;
+            return;
+        }
+        loop {
+            if self.floor.is_none() && sand.1 >= self.abyss {
+                self.done = true;
+                return;
+            }
+            if self.floor == Some(sand.1 + 1) {
+                break;
+            } else if !world.contains(&(sand.0, sand.1 + 1)) {
+                sand.1 += 1;
+            } else if !world.contains(&(sand.0 - 1, sand.1 + 1)) {
+                sand.1 += 1;
+                sand.0 -= 1;
+            } else if !world.contains(&(sand.0 + 1, sand.1 + 1)) {
+                sand.1 += 1;
+                sand.0 += 1;
+            } else {
+                break;
+            }
+        }
+        world.insert(sand);
+        self.last_rest = Some(sand);
+        self.grains += 1;
+    }
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    fn snapshot(&self) -> (i32, Option<Coord>) {
+        (self.grains, self.last_rest)
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    let mut cave = Cave::new(input, false);
+    simulation::run(&mut cave);
+    cave.grains
+}
+
+pub fn part2(input: &str) -> i32 {
+    let mut cave = Cave::new(input, true);
+    simulation::run(&mut cave);
+    cave.grains
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input());
+    println!("{result}");
+}
@@ -1,8 +1,9 @@
-use std::collections::HashSet;
-use std::fs;
+use crate::input;
+use crate::interval::{Interval, IntervalSet};
+use crate::parse::{self, ParseResult};
 
 #[derive(Debug, Clone)]
-struct Coord {
+pub(crate) struct Coord {
     pub x: i64,
     pub y: i64,
 }
@@ -27,60 +28,53 @@ impl Coord {
     }
 }
 
-fn read_input() -> Vec<(Coord, Coord)> {
-    let file_path = "inputs/day15.txt";
-    let input = fs::read_to_string(file_path).expect("Should have been able to read the file");
-    parse_input(input)
+pub const INPUT: &str = "inputs/2022/day15.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
 }
 
-fn parse_input(input: String) -> Vec<(Coord, Coord)> {
-    let mut coords = vec![];
-    for line in input.lines() {
-        let (_, line) = line.split_once("x=").unwrap();
-        let (sensor_x, line) = line.split_once(',').unwrap();
-        let (_, line) = line.split_once("y=").unwrap();
-        let (sensor_y, line) = line.split_once(':').unwrap();
-        let (_, line) = line.split_once("x=").unwrap();
-        let (beacon_x, line) = line.split_once(',').unwrap();
-        let (_, beacon_y) = line.split_once("y=").unwrap();
-        coords.push((
-            Coord {
-                x: sensor_x.parse().unwrap(),
-                y: sensor_y.parse().unwrap(),
-            },
-            Coord {
-                x: beacon_x.parse().unwrap(),
-                y: beacon_y.parse().unwrap(),
-            },
-        ))
-    }
-    coords
+/// Parses `x=X, y=Y`.
+fn parse_coord(str: &str) -> ParseResult<Coord> {
+    let (x, y) = parse::split_once(parse::strip_prefix(str, "x=")?, ", y=")?;
+    Ok(Coord {
+        x: parse::number(x)?,
+        y: parse::number(y)?,
+    })
 }
 
-pub fn solve1() {
-    let coords = read_input();
-    let mut blocked_x = HashSet::new();
-    for (sensor, beacon) in coords {
-        let dist = sensor.m_dist(&beacon);
-        let x1 = sensor.x;
-        let y1 = sensor.y;
-        let y2 = 2000000;
-        let y_diff = y1.abs_diff(y2);
-        if y_diff > dist {
-            continue;
-        }
-        let x_dist = dist - y_diff;
-        let x_min = x1 - x_dist as i64;
-        let x_max = x1 + x_dist as i64;
-        (x_min..=x_max).for_each(|x| {
-            blocked_x.insert(x);
-        });
-        if beacon.y == 2000000 {
-            blocked_x.remove(&beacon.x);
-        }
-    }
-    let result = blocked_x.len();
-    println!("{result}");
+pub(crate) fn parse_input(input: &str) -> ParseResult<Vec<(Coord, Coord)>> {
+    parse::lines(input, |line| {
+        let line = parse::strip_prefix(line, "Sensor at ")?;
+        let (sensor, beacon) = parse::split_once(line, ": closest beacon is at ")?;
+        Ok((parse_coord(sensor)?, parse_coord(beacon)?))
+    })
+}
+
+/// The row part 1 asks about.
+const ROW: i64 = 2000000;
+
+/// The positions in row `y` where no beacon can be: those some sensor is at least as close to as
+/// to its own beacon, except where a beacon is.
+pub(crate) fn row_coverage(sensors: &[(Coord, Coord)], y: i64) -> IntervalSet {
+    let covered: IntervalSet = sensors
+        .iter()
+        .filter_map(|(sensor, beacon)| {
+            let reach = sensor.m_dist(beacon).checked_sub(sensor.y.abs_diff(y))? as i64;
+            Interval::new(sensor.x - reach, sensor.x + reach)
+        })
+        .collect();
+    let beacons: IntervalSet = sensors
+        .iter()
+        .filter(|(_, beacon)| beacon.y == y)
+        .map(|(_, beacon)| Interval::point(beacon.x))
+        .collect();
+    covered.difference(&beacons)
+}
+
+pub fn part1(input: &str) -> u64 {
+    let coords = parse_input(input).unwrap();
+    row_coverage(&coords, ROW).len().try_into().unwrap()
 }
 
 struct Rect {
@@ -105,8 +99,8 @@ impl Rect {
     }
 }
 
-pub fn solve2() {
-    let coords = read_input();
+pub fn part2(input: &str) -> i64 {
+    let coords = parse_input(input).unwrap();
     let mut stack = vec![];
     stack.push(Rect {
         point: Coord { x: 0, y: 0 },
@@ -123,8 +117,7 @@ pub fn solve2() {
         let half_y = current.len_y / 2;
 
         if current.len_x == 0 && current.len_y == 0 {
-            println!("{:?}", current.point.x * 4000000 + current.point.y);
-            break;
+            return current.point.x * 4000000 + current.point.y;
         }
 
         stack.push(Rect {
@@ -157,4 +150,15 @@ pub fn solve2() {
             });
         }
     }
+    panic!("no uncovered position found")
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input());
+    println!("{result:?}");
 }
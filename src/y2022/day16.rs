@@ -0,0 +1,480 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use rayon::prelude::*;
+
+use crate::checkpoint::{Checkpoint, Reader, Writer};
+use crate::control::{Cancelled, Control};
+use crate::events::{Event, EventLog};
+use crate::input;
+use crate::inspect::Inspection;
+use crate::parse::{self, ParseResult};
+
+#[derive(Clone, Debug)]
+pub(crate) struct Room {
+    name: String,
+    flow_rate: i32,
+    tunnels: Vec<String>,
+    shortest_paths: Vec<(String, i32)>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct State {
+    cost: i32,
+    position: String,
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Notice that the we flip the ordering on costs.
+        // In case of a tie we compare positions - this step is necessary
+        // to make implementations of `PartialEq` and `Ord` consistent.
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| self.position.cmp(&other.position))
+    }
+}
+
+// `PartialOrd` needs to be implemented as well.
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub const INPUT: &str = "inputs/2022/day16.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+pub(crate) fn parse_input(input: &str) -> ParseResult<Vec<Room>> {
+    parse::lines(input, |line| {
+        let (_, line) = parse::split_once(line, "Valve ")?;
+        let (name, line) = parse::split_once(line, " has flow rate=")?;
+        let (flow_rate, line) = parse::split_once(line, "; tunnels lead to valves ")
+            .or_else(|_| parse::split_once(line, "; tunnel leads to valve "))?;
+        let tunnels = line.split(", ");
+        Ok(Room {
+            name: name.to_string(),
+            flow_rate: parse::number(flow_rate)?,
+            tunnels: tunnels.map(|t| t.to_string()).collect(),
+            shortest_paths: vec![],
+        })
+    })
+}
+
+fn eval(rooms: &[Room], mut steps: Vec<String>, max_time: i32) -> i32 {
+    steps.reverse();
+    assert_eq!(steps.pop().unwrap(), "AA");
+    let mut released_pressure: i32 = 0;
+    let mut open_valves = HashSet::new();
+    let mut current_room = "AA".to_string();
+    let mut minute_counter = 0;
+
+    while minute_counter < max_time {
+        let open_pressure = rooms
+            .iter()
+            .map(|r| {
+                if open_valves.contains(r.name.as_str()) {
+                    r.flow_rate
+                } else {
+                    0
+                }
+            })
+            .sum::<i32>();
+
+        match steps.pop() {
+            None => {
+                released_pressure += (max_time - minute_counter) * open_pressure;
+                break;
+            }
+            Some(step) => {
+                let room = rooms.iter().find(|r| r.name == current_room).unwrap();
+                let distance = match room.shortest_paths.iter().find(|ps| ps.0 == step) {
+                    None => {
+                        // room == AA == step
+                        dbg!(&room);
+                        dbg!(&step);
+                        dbg!(&steps);
+                        0
+                    }
+                    Some(res) => res.1,
+                };
+                minute_counter += distance + 1;
+                assert!(minute_counter <= max_time);
+                released_pressure += (distance + 1) * open_pressure;
+
+                current_room = step;
+                if !open_valves.insert(current_room.clone()) {
+                    panic!("double open {current_room}")
+                }
+            }
+        }
+    }
+    released_pressure
+}
+
+fn populate_room_graph(rooms: &mut Vec<Room>) {
+    let room_clone = rooms.clone();
+    for room in rooms {
+        let paths = shortest_path(&room_clone, room.name.clone());
+        room.shortest_paths = paths;
+    }
+}
+
+fn shortest_path(rooms: &[Room], start: String) -> Vec<(String, i32)> {
+    let mut visited: HashMap<String, i32> = HashMap::new();
+    let mut priority_queue = BinaryHeap::new();
+    priority_queue.push(State {
+        cost: 0,
+        position: start.clone(),
+    });
+
+    while let Some(current) = priority_queue.pop() {
+        let mut skip = false;
+        visited
+            .entry(current.position.clone())
+            .and_modify(|known_shortest| {
+                if current.cost < *known_shortest {
+                    *known_shortest = current.cost;
+                } else {
+                    skip = true;
+                }
+            })
+            .or_insert(current.cost);
+        if skip {
+            continue;
+        }
+        let neighbors = rooms
+            .iter()
+            .find(|r| r.name == current.position)
+            .unwrap()
+            .tunnels
+            .clone();
+        for n in neighbors {
+            priority_queue.push(State {
+                cost: current.cost + 1,
+                position: n,
+            })
+        }
+    }
+    let mut result: Vec<_> = visited.into_iter().filter(|v| v.0 != start).collect();
+    result.sort();
+    result
+}
+
+impl Room {
+    fn dist_to(&self, other: &str) -> i32 {
+        self.shortest_paths.iter().find(|p| p.0 == other).unwrap().1
+    }
+}
+
+fn path_length(rooms: &[Room], path: &[String]) -> i32 {
+    let mut length = 0;
+    let mut current: &Room = path
+        .first()
+        .and_then(|p| rooms.iter().find(|r| r.name == *p))
+        .unwrap();
+    for path in path.iter().skip(1) {
+        length += current.dist_to(path) + 1;
+        current = rooms.iter().find(|r| r.name == *path).unwrap();
+    }
+    length
+}
+
+/// State of the search for the best valve order: partial paths still to extend, the complete
+/// paths found so far and, once all paths are known, how many of them were scored.
+struct Search {
+    frontier: Vec<Vec<String>>,
+    paths: Vec<Vec<String>>,
+    scored: usize,
+    best: i32,
+}
+
+impl Search {
+    fn new() -> Self {
+        Search {
+            frontier: vec![vec!["AA".to_string()]],
+            paths: vec![],
+            scored: 0,
+            best: 0,
+        }
+    }
+}
+
+impl Checkpoint for Search {
+    fn write(&self, out: &mut Writer) {
+        out.field("scored", self.scored);
+        out.field("best", self.best);
+        out.list("frontier", self.frontier.iter().map(|p| p.join(" ")));
+        out.list("paths", self.paths.iter().map(|p| p.join(" ")));
+    }
+
+    fn read(input: &mut Reader) -> Option<Self> {
+        let split = |p: &str| p.split(' ').map(String::from).collect();
+        Some(Search {
+            scored: input.field("scored")?,
+            best: input.field("best")?,
+            frontier: input.list("frontier")?.into_iter().map(split).collect(),
+            paths: input.list("paths")?.into_iter().map(split).collect(),
+        })
+    }
+}
+
+/// How many partial paths are extended, and how many complete paths are scored, between two
+/// checks of the control. Scoring a path for part 2 looks at every other path, so it gets
+/// smaller chunks.
+const CHUNK: usize = 1024;
+const SCORE_CHUNK: usize = 64;
+
+fn extend(rooms: &[Room], current_path: &[String], max_time: i32) -> Vec<Vec<String>> {
+    rooms
+        .iter()
+        .filter(|r| !current_path.contains(&r.name) && r.flow_rate > 0)
+        .map(|r| {
+            let mut path = current_path.to_vec();
+            path.push(r.name.clone());
+            path
+        })
+        .filter(|p| path_length(rooms, p) <= max_time)
+        .collect()
+}
+
+/// Extends the frontier until every path runs out of time or valves.
+fn bfs(
+    rooms: &[Room],
+    search: &mut Search,
+    max_time: i32,
+    control: &Control,
+) -> Result<(), Cancelled> {
+    while !search.frontier.is_empty() {
+        control.check_with(search)?;
+        control.report("paths", search.paths.len() as u64, None);
+        let chunk = search
+            .frontier
+            .split_off(search.frontier.len().saturating_sub(CHUNK));
+        let extended: Vec<_> = chunk
+            .into_par_iter()
+            .map(|p| {
+                let next = extend(rooms, &p, max_time);
+                (p, next)
+            })
+            .collect();
+        for (path, next) in extended {
+            if next.is_empty() {
+                search.paths.push(path);
+            } else {
+                search.frontier.extend(next);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The outcome of [`find_best`], with every path and its released pressure so the best ones can
+/// be looked up again.
+struct Found {
+    rooms: Vec<Room>,
+    paths: Vec<Vec<String>>,
+    values: Vec<i32>,
+    best: i32,
+}
+
+impl Found {
+    /// Logs a `valve_opened` event for every valve `by` opens walking `path`.
+    fn log_path(&self, path: &[String], max_time: i32, by: &str, log: &EventLog) {
+        let mut minute = 0;
+        for pair in path.windows(2) {
+            let from = self.rooms.iter().find(|r| r.name == pair[0]).unwrap();
+            let to = self.rooms.iter().find(|r| r.name == pair[1]).unwrap();
+            minute += from.dist_to(&to.name) + 1;
+            log.write(
+                &Event::new("valve_opened")
+                    .with("minute", minute)
+                    .with("valve", to.name.as_str())
+                    .with("flow_rate", to.flow_rate)
+                    .with("by", by)
+                    .with("released", to.flow_rate * (max_time - minute)),
+            );
+        }
+    }
+}
+
+/// Finds all paths, then scores them in chunks, each path getting the best value of
+/// `score(index, values)` where `values` holds the released pressure of every path.
+fn find_best(
+    input: &str,
+    max_time: i32,
+    control: &Control,
+    score: impl Fn(&[Vec<String>], &[i32], usize) -> i32 + Sync,
+) -> Result<Found, Cancelled> {
+    let mut rooms = parse_input(input).unwrap();
+    populate_room_graph(&mut rooms);
+    let mut search = control.resume().unwrap_or_else(Search::new);
+    bfs(&rooms, &mut search, max_time, control)?;
+    let values: Vec<i32> = search
+        .paths
+        .par_iter()
+        .map(|p| eval(&rooms, p.clone(), max_time))
+        .collect();
+    let total = Some(search.paths.len() as u64);
+    while search.scored < search.paths.len() {
+        control.check_with(&search)?;
+        control.report("paths", search.scored as u64, total);
+        let end = (search.scored + SCORE_CHUNK).min(search.paths.len());
+        let best = (search.scored..end)
+            .into_par_iter()
+            .map(|i| score(&search.paths, &values, i))
+            .max()
+            .unwrap_or(0);
+        search.best = search.best.max(best);
+        search.scored = end;
+    }
+    Ok(Found {
+        rooms,
+        paths: search.paths,
+        values,
+        best: search.best,
+    })
+}
+
+fn disjoint(path1: &[String], path2: &[String]) -> bool {
+    !path1.iter().any(|r| r != "AA" && path2.contains(r))
+}
+
+pub fn part1(input: &str, control: &Control) -> Result<i32, Cancelled> {
+    let found = find_best(input, 30, control, |_, values, i| values[i])?;
+    if let Some(log) = control.events() {
+        if let Some(i) = found.values.iter().position(|v| *v == found.best) {
+            found.log_path(&found.paths[i], 30, "you", log);
+        }
+    }
+    Ok(found.best)
+}
+
+pub fn part2(input: &str, control: &Control) -> Result<i32, Cancelled> {
+    let found = find_best(input, 26, control, |paths, values, i| {
+        let path1 = &paths[i];
+        paths
+            .iter()
+            .zip(values)
+            .filter(|(path2, _)| disjoint(path1, path2))
+            .map(|(_, value2)| values[i] + value2)
+            .max()
+            .unwrap_or(0)
+    })?;
+    if let Some(log) = control.events() {
+        // Looking the best pair up again is quadratic, so it is only done when logging.
+        let (paths, values) = (&found.paths, &found.values);
+        let pair = (0..paths.len())
+            .flat_map(|i| (i..paths.len()).map(move |j| (i, j)))
+            .find(|&(i, j)| values[i] + values[j] == found.best && disjoint(&paths[i], &paths[j]));
+        if let Some((i, j)) = pair {
+            found.log_path(&paths[i], 26, "you", log);
+            found.log_path(&paths[j], 26, "elephant", log);
+        }
+    }
+    Ok(found.best)
+}
+
+/// The number of valves with flow decides how many paths the search has to enumerate.
+pub fn inspect(input: &str) -> Inspection {
+    let mut inspection = Inspection::default();
+    let mut rooms = match parse_input(input) {
+        Ok(rooms) => rooms,
+        Err(e) => {
+            inspection.warn(format!("the input does not parse: {e}"));
+            return inspection;
+        }
+    };
+    let with_flow: Vec<&Room> = rooms.iter().filter(|r| r.flow_rate > 0).collect();
+    inspection.fact("valves", rooms.len());
+    inspection.fact("valves with flow", with_flow.len());
+    inspection.fact(
+        "total flow",
+        with_flow.iter().map(|r| r.flow_rate).sum::<i32>(),
+    );
+    inspection.fact(
+        "most tunnels",
+        rooms.iter().map(|r| r.tunnels.len()).max().unwrap_or(0),
+    );
+    if with_flow.len() > 15 {
+        inspection.warn(format!(
+            "{} valves with flow, the number of paths to search grows factorially with it",
+            with_flow.len()
+        ));
+    }
+
+    let mut complete = true;
+    for room in &rooms {
+        for tunnel in &room.tunnels {
+            if !rooms.iter().any(|r| r.name == *tunnel) {
+                complete = false;
+                inspection.warn(format!(
+                    "valve {} leads to unknown valve {tunnel}",
+                    room.name
+                ));
+            }
+        }
+    }
+    match rooms.iter().find(|r| r.name == "AA") {
+        None => inspection.warn("there is no valve AA to start at"),
+        Some(start) if start.flow_rate > 0 => {
+            inspection.warn("valve AA has flow, but the search never opens the valve it starts at")
+        }
+        Some(_) if complete => {
+            populate_room_graph(&mut rooms);
+            let start = rooms.iter().find(|r| r.name == "AA").unwrap();
+            let unreachable = rooms
+                .iter()
+                .filter(|r| r.flow_rate > 0 && !start.shortest_paths.iter().any(|p| p.0 == r.name))
+                .count();
+            if unreachable > 0 {
+                inspection.warn(format!(
+                    "{unreachable} valves with flow cannot be reached from AA"
+                ));
+            }
+        }
+        Some(_) => {}
+    }
+    inspection
+}
+
+pub fn solve1() {
+    let result = part1(&read_input(), &Control::default()).unwrap();
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input(), &Control::default()).unwrap();
+    println!("{result}");
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day16::*;
+
+    #[test]
+    fn it_works() {
+        let input = "Valve AA has flow rate=0; tunnels lead to valves BB, CC
+        Valve BB has flow rate=13; tunnels lead to valves AA, DD
+        Valve CC has flow rate=2; tunnels lead to valves AA, DD, EE
+        Valve DD has flow rate=2; tunnels lead to valves CC, BB, EE
+        Valve EE has flow rate=2; tunnels lead to valves DD, CC";
+        let mut rooms = parse_input(input).unwrap();
+        populate_room_graph(&mut rooms);
+        let result = eval(
+            &rooms,
+            vec![
+                "AA".to_string(),
+                "BB".to_string(),
+                "EE".to_string(),
+                "CC".to_string(),
+            ],
+            30,
+        );
+        assert_eq!(result, 28 * 13 + 25 * 2 + 23 * 2)
+    }
+}
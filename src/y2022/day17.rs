@@ -0,0 +1,288 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use nom::AsChar;
+
+use crate::control::{Cancelled, Control};
+use crate::events::Event;
+use crate::input;
+use crate::parse::{ParseError, ParseResult};
+use crate::simulation::{self, Simulation};
+
+pub const INPUT: &str = "inputs/2022/day17.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+pub(crate) fn parse_input(input: &str) -> ParseResult<String> {
+    let jets = input.trim();
+    if jets.is_empty() {
+        return Err(ParseError::new("expected a pattern of jets"));
+    }
+    match jets.chars().find(|&c| c != '<' && c != '>') {
+        Some(c) => Err(ParseError::new(format!("unexpected jet {c:?}"))),
+        None => Ok(jets.to_string()),
+    }
+}
+
+type Coord = (i64, i64);
+
+struct World {
+    stones: HashSet<Coord>,
+    top: i64,
+    levels: [i64; 7],
+}
+
+impl World {
+    pub(crate) fn highest(&self) -> i64 {
+        self.top
+    }
+
+    pub(crate) fn insert(&mut self, coord: Coord) {
+        self.top = self.top.max(coord.1);
+        self.levels[coord.0 as usize] = self.levels[coord.0 as usize].max(coord.1);
+        self.stones.insert(coord);
+    }
+
+    pub(crate) fn new(y: i64) -> Self {
+        World {
+            stones: (0..7).map(|x| (x, y)).collect(),
+            top: y,
+            levels: [y; 7],
+        }
+    }
+
+    /// How far below the top the highest stone of each column is.
+    pub(crate) fn profile(&self) -> [i64; 7] {
+        self.levels.map(|l| self.top - l)
+    }
+}
+
+impl fmt::Display for World {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in (0..=self.top).rev() {
+            for x in 0..7 {
+                let c = if self.stones.contains(&(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                };
+                write!(f, "{c}")?;
+            }
+            if y > 0 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Shape {
+    // +###
+    Flat,
+    // .#.
+    // ###
+    // +#.
+    Plus,
+    // ..#
+    // ..#
+    // +##
+    ReverseL,
+    // #
+    // #
+    // #
+    // +
+    Bar,
+    // ##
+    // +#
+    Square,
+}
+
+impl Shape {
+    pub(crate) fn fall(&self, coord: &mut Coord, world: &mut World) -> bool {
+        let new_coord = (coord.0, coord.1 - 1);
+        let coords: Vec<Coord> = self.occupies(&new_coord);
+        let collides = coords
+            .into_iter()
+            .any(|coord| world.stones.contains(&coord));
+        if collides {
+            self.occupies(coord)
+                .into_iter()
+                .for_each(|coord| world.insert(coord));
+            true
+        } else {
+            *coord = new_coord;
+            false
+        }
+    }
+
+    fn occupies(&self, coord: &Coord) -> Vec<Coord> {
+        let (x, y) = *coord;
+        match self {
+            Shape::Flat => {
+                vec![(x, y), (x + 1, y), (x + 2, y), (x + 3, y)]
+            }
+            Shape::Plus => {
+                vec![
+                    (x + 1, y),
+                    (x, y + 1),
+                    (x + 1, y + 1),
+                    (x + 2, y + 1),
+                    (x + 1, y + 2),
+                ]
+            }
+            Shape::ReverseL => {
+                vec![
+                    (x, y),
+                    (x + 1, y),
+                    (x + 2, y),
+                    (x + 2, y + 1),
+                    (x + 2, y + 2),
+                ]
+            }
+            Shape::Bar => {
+                vec![(x, y), (x, y + 1), (x, y + 2), (x, y + 3)]
+            }
+            Shape::Square => {
+                vec![(x, y), (x + 1, y), (x, y + 1), (x + 1, y + 1)]
+            }
+        }
+    }
+}
+
+impl Shape {
+    #[allow(clippy::manual_range_contains)]
+    pub(crate) fn jet(&self, jet: i64, coord: &mut Coord, world: &World) {
+        let new_coord = (coord.0 + jet, coord.1);
+        let coords: Vec<Coord> = self.occupies(&new_coord);
+        let collides = coords
+            .into_iter()
+            .any(|(x, y)| x < 0 || x > 6 || world.stones.contains(&(x, y)));
+        if !collides {
+            *coord = new_coord;
+        }
+    }
+}
+
+fn jet_at(jets: &str, idx: &mut usize) -> i64 {
+    let bytes = jets.as_bytes();
+    let res = if bytes[*idx].as_char() == '<' { -1 } else { 1 };
+    *idx += 1;
+    if *idx == bytes.len() {
+        *idx = 0
+    }
+    res
+}
+
+const SHAPES: [Shape; 5] = [
+    Shape::Flat,
+    Shape::Plus,
+    Shape::ReverseL,
+    Shape::Bar,
+    Shape::Square,
+];
+
+/// The chamber rocks fall into, one step being one rock falling until it comes to rest.
+struct Tower {
+    world: World,
+    jets: String,
+    jet_index: usize,
+    shape_index: usize,
+    rocks: usize,
+    /// The shape of the last rock and where it came to rest.
+    landed: Option<(&'static Shape, Coord)>,
+}
+
+impl Tower {
+    fn new(jets: String) -> Self {
+        Tower {
+            world: World::new(0),
+            jets,
+            jet_index: 0,
+            shape_index: 0,
+            rocks: 0,
+            landed: None,
+        }
+    }
+}
+
+impl Simulation for Tower {
+    /// The next shape, the next jet and the shape of the surface. Rocks never fall deep enough
+    /// into the tower for the stones below the surface to matter.
+    type Snapshot = (usize, usize, [i64; 7]);
+
+    fn step(&mut self) {
+        let shape = &SHAPES[self.shape_index];
+        self.shape_index = (self.shape_index + 1) % SHAPES.len();
+        let mut coord = (2, self.world.highest() + 4);
+        loop {
+            let jet = jet_at(&self.jets, &mut self.jet_index);
+            shape.jet(jet, &mut coord, &self.world);
+            if shape.fall(&mut coord, &mut self.world) {
+                break;
+            }
+        }
+        self.rocks += 1;
+        self.landed = Some((shape, coord));
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.shape_index, self.jet_index, self.world.profile())
+    }
+
+    fn events(&self) -> Vec<Event> {
+        let Some((shape, (x, y))) = self.landed else {
+            return vec![];
+        };
+        vec![Event::new("rock_landed")
+            .with("rock", self.rocks)
+            .with("shape", format!("{shape:?}"))
+            .with("x", x)
+            .with("y", y)
+            .with("height", self.world.highest())]
+    }
+}
+
+impl fmt::Display for Tower {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.world)
+    }
+}
+
+pub fn part1(input: &str, control: &Control) -> Result<i64, Cancelled> {
+    let mut tower = Tower::new(parse_input(input).unwrap());
+    simulation::run_controlled(&mut tower, Some(2022), control, "rocks")?;
+    Ok(tower.world.highest())
+}
+
+/// Only the rocks before the first cycle and those after the last full one are simulated, so
+/// those are the ones in the event log.
+pub fn part2(input: &str, control: &Control) -> Result<i64, Cancelled> {
+    let jets = parse_input(input).unwrap();
+    let rocks: usize = 1_000_000_000_000;
+    let cycle = simulation::find_cycle(&mut Tower::new(jets.clone()), 100_000)
+        .expect("the tower should repeat itself");
+
+    // The height grows by the same amount every cycle, so only the rocks before the first cycle
+    // and those after the last full one need to be simulated.
+    let mut tower = Tower::new(jets);
+    let reduced = cycle.reduce(rocks);
+    simulation::run_controlled(&mut tower, Some(reduced), control, "rocks")?;
+    let height = tower.world.highest();
+    simulation::run_for(&mut tower, cycle.length);
+    let growth = tower.world.highest() - height;
+    let cycles = ((rocks - reduced) / cycle.length) as i64;
+    Ok(height + cycles * growth)
+}
+
+pub fn solve1() {
+    let result = part1(&read_input(), &Control::default()).unwrap();
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input(), &Control::default()).unwrap();
+    println!("{result}");
+}
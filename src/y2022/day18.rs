@@ -1,8 +1,10 @@
 use std::collections::HashSet;
-use std::fs;
+
+use crate::input;
+use crate::parse::{self, ParseResult};
 
 #[derive(Debug, Eq, PartialEq, Hash, Clone)]
-struct Cube {
+pub(crate) struct Cube {
     x: i32,
     y: i32,
     z: i32,
@@ -25,28 +27,26 @@ impl Cube {
     }
 }
 
-fn read_input() -> Vec<Cube> {
-    let file_path = "inputs/day18.txt";
-    let input = fs::read_to_string(file_path).expect("Should have been able to read the file");
-    parse_input(input)
+pub const INPUT: &str = "inputs/2022/day18.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
 }
 
-fn parse_input(input: String) -> Vec<Cube> {
-    let mut cubes = vec![];
-    for line in input.lines() {
-        let (x, rest) = line.split_once(',').unwrap();
-        let (y, z) = rest.split_once(',').unwrap();
-        cubes.push(Cube {
-            x: x.parse().unwrap(),
-            y: y.parse().unwrap(),
-            z: z.parse().unwrap(),
+pub(crate) fn parse_input(input: &str) -> ParseResult<Vec<Cube>> {
+    parse::lines(input, |line| {
+        let (x, rest) = parse::split_once(line, ",")?;
+        let (y, z) = parse::split_once(rest, ",")?;
+        Ok(Cube {
+            x: parse::number(x)?,
+            y: parse::number(y)?,
+            z: parse::number(z)?,
         })
-    }
-    cubes
+    })
 }
 
-pub fn solve1() {
-    let cubes = read_input();
+pub fn part1(input: &str) -> usize {
+    let cubes = parse_input(input).unwrap();
     let mut surface_area = 0;
 
     for cube in cubes.iter() {
@@ -58,7 +58,7 @@ pub fn solve1() {
         }
     }
 
-    println!("{surface_area}");
+    surface_area
 }
 
 #[derive(Default)]
@@ -79,10 +79,10 @@ struct Solve2 {
 }
 
 impl Solve2 {
-    pub fn new() -> Self {
+    pub fn new(cubes: Vec<Cube>) -> Self {
         let mut world = HashSet::new();
         let mut bounds: Bounds = Default::default();
-        for cube in read_input() {
+        for cube in cubes {
             bounds.min_x = cube.x.min(bounds.min_x);
             bounds.min_y = cube.y.min(bounds.min_y);
             bounds.min_z = cube.z.min(bounds.min_z);
@@ -149,7 +149,7 @@ impl Solve2 {
         false
     }
 
-    pub fn solve2(&mut self) {
+    pub fn solve2(&mut self) -> usize {
         let mut surface_area = 0;
 
         for cube in self.world.clone().iter() {
@@ -161,10 +161,20 @@ impl Solve2 {
             }
         }
 
-        println!("{surface_area}");
+        surface_area
     }
 }
 
+pub fn part2(input: &str) -> usize {
+    Solve2::new(parse_input(input).unwrap()).solve2()
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}");
+}
+
 pub fn solve2() {
-    Solve2::new().solve2()
+    let result = part2(&read_input());
+    println!("{result}");
 }
@@ -0,0 +1,343 @@
+use crate::checkpoint::{Checkpoint, Reader, Writer};
+use crate::control::{Cancelled, Control};
+use crate::input;
+use crate::parse::{self, ParseResult};
+use crate::y2022::day19::Resource::{Clay, Geode, Obsidian, Ore};
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+enum Resource {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+impl Resource {
+    fn all() -> Vec<Resource> {
+        vec![Ore, Clay, Obsidian, Geode]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Blueprint {
+    id: i64,
+    ore: i64,
+    clay: i64,
+    obsidian: (i64, i64),
+    geode: (i64, i64),
+}
+
+impl Blueprint {
+    fn max_ore_consumption(&self) -> i64 {
+        self.ore
+            .max(self.clay)
+            .max(self.obsidian.0)
+            .max(self.geode.0)
+    }
+
+    fn max_clay_consumption(&self) -> i64 {
+        self.obsidian.1
+    }
+
+    fn max_obsidian_consumption(&self) -> i64 {
+        self.geode.1
+    }
+}
+
+pub const INPUT: &str = "inputs/2022/day19.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+pub(crate) fn parse_input(input: &str) -> ParseResult<Vec<Blueprint>> {
+    parse::lines(input, |line| {
+        let (_, line) = parse::split_once(line, "Blueprint ")?;
+        let (id, line) = parse::split_once(line, ": Each ore robot costs ")?;
+        let (ore, line) = parse::split_once(line, " ore. Each clay robot costs ")?;
+        let (clay, line) = parse::split_once(line, " ore. Each obsidian robot costs ")?;
+        let (obsidian_ore, line) = parse::split_once(line, " ore and ")?;
+        let (obsidian_clay, line) = parse::split_once(line, " clay. Each geode robot costs ")?;
+        let (geode_ore, line) = parse::split_once(line, " ore and ")?;
+        let (geode_obsidian, _) = parse::split_once(line, " obsidian.")?;
+        Ok(Blueprint {
+            id: parse::number(id)?,
+            ore: parse::number(ore)?,
+            clay: parse::number(clay)?,
+            obsidian: (parse::number(obsidian_ore)?, parse::number(obsidian_clay)?),
+            geode: (parse::number(geode_ore)?, parse::number(geode_obsidian)?),
+        })
+    })
+}
+
+struct Evaluator {
+    blueprint: Blueprint,
+    plan: Vec<Resource>,
+    ore: i64,
+    clay: i64,
+    obsidian: i64,
+    geode: i64,
+    ore_robot: i64,
+    clay_robot: i64,
+    obsidian_robot: i64,
+    geode_robot: i64,
+}
+
+struct EvalResult {
+    produced_geodes: i64,
+    finished: bool,
+    ore_saturated: bool,
+    clay_saturated: bool,
+    obsidian_saturated: bool,
+}
+
+impl EvalResult {
+    fn is_saturated(&self, resource: &Resource) -> bool {
+        match resource {
+            Ore => self.ore_saturated,
+            Clay => self.clay_saturated,
+            Obsidian => self.obsidian_saturated,
+            Geode => false,
+        }
+    }
+}
+
+impl Evaluator {
+    fn new(blueprint: Blueprint, mut plan: Vec<Resource>) -> Evaluator {
+        plan.reverse();
+        Evaluator {
+            blueprint,
+            plan,
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geode: 0,
+            ore_robot: 1,
+            clay_robot: 0,
+            obsidian_robot: 0,
+            geode_robot: 0,
+        }
+    }
+
+    fn eval(&mut self, minutes: i64) -> EvalResult {
+        let mut ore_saturated = false;
+        let mut clay_saturated = false;
+        let mut obsidian_saturated = false;
+        let mut captured = false;
+        for i in 0..minutes {
+            self.tick();
+            if !captured && self.plan.is_empty() {
+                captured = true;
+                let remaining_time = minutes - i - 1;
+                ore_saturated = self.ore + self.ore_robot * remaining_time
+                    > remaining_time * self.blueprint.max_ore_consumption();
+                clay_saturated = self.clay + self.clay_robot * remaining_time
+                    > remaining_time * self.blueprint.max_clay_consumption();
+                obsidian_saturated = self.obsidian + self.obsidian_robot * remaining_time
+                    > remaining_time * self.blueprint.max_obsidian_consumption();
+            }
+        }
+        EvalResult {
+            produced_geodes: self.geode,
+            finished: self.plan.is_empty(),
+            ore_saturated,
+            clay_saturated,
+            obsidian_saturated,
+        }
+    }
+
+    fn tick(&mut self) {
+        let new_ore = self.ore_robot;
+        let new_clay = self.clay_robot;
+        let new_obsidian = self.obsidian_robot;
+        let new_geode = self.geode_robot;
+
+        match self.plan.last() {
+            Some(Ore) if self.ore >= self.blueprint.ore => {
+                self.ore_robot += 1;
+                self.ore -= self.blueprint.ore;
+                self.plan.pop();
+            }
+            Some(Clay) if self.ore >= self.blueprint.clay => {
+                self.clay_robot += 1;
+                self.ore -= self.blueprint.clay;
+                self.plan.pop();
+            }
+            Some(Obsidian)
+                if self.ore >= self.blueprint.obsidian.0
+                    && self.clay >= self.blueprint.obsidian.1 =>
+            {
+                self.obsidian_robot += 1;
+                self.ore -= self.blueprint.obsidian.0;
+                self.clay -= self.blueprint.obsidian.1;
+                self.plan.pop();
+            }
+            Some(Geode)
+                if self.ore >= self.blueprint.geode.0
+                    && self.obsidian >= self.blueprint.geode.1 =>
+            {
+                self.geode_robot += 1;
+                self.ore -= self.blueprint.geode.0;
+                self.obsidian -= self.blueprint.geode.1;
+                self.plan.pop();
+            }
+            _ => {}
+        }
+
+        self.ore += new_ore;
+        self.clay += new_clay;
+        self.obsidian += new_obsidian;
+        self.geode += new_geode;
+    }
+}
+
+/// State of the search over all blueprints: the index of the current blueprint, the result
+/// combined from the ones before it and the plan search for the current one.
+struct Search {
+    blueprint: usize,
+    result: i64,
+    worklist: Vec<Vec<Resource>>,
+    max_geodes: i64,
+    best_plan: Vec<Resource>,
+}
+
+impl Search {
+    fn new(result: i64) -> Self {
+        Search {
+            blueprint: 0,
+            result,
+            worklist: vec![vec![]],
+            max_geodes: 0,
+            best_plan: vec![],
+        }
+    }
+}
+
+/// Plans are written as one letter per robot, `-` stands for the empty plan.
+fn write_plan(plan: &[Resource]) -> String {
+    if plan.is_empty() {
+        return "-".to_string();
+    }
+    plan.iter()
+        .map(|r| match r {
+            Ore => 'o',
+            Clay => 'c',
+            Obsidian => 'b',
+            Geode => 'g',
+        })
+        .collect()
+}
+
+fn read_plan(plan: &str) -> Option<Vec<Resource>> {
+    if plan == "-" {
+        return Some(vec![]);
+    }
+    plan.chars()
+        .map(|c| match c {
+            'o' => Some(Ore),
+            'c' => Some(Clay),
+            'b' => Some(Obsidian),
+            'g' => Some(Geode),
+            _ => None,
+        })
+        .collect()
+}
+
+impl Checkpoint for Search {
+    fn write(&self, out: &mut Writer) {
+        out.field("blueprint", self.blueprint);
+        out.field("result", self.result);
+        out.field("max_geodes", self.max_geodes);
+        out.field("best_plan", write_plan(&self.best_plan));
+        out.list("worklist", self.worklist.iter().map(|p| write_plan(p)));
+    }
+
+    fn read(input: &mut Reader) -> Option<Self> {
+        Some(Search {
+            blueprint: input.field("blueprint")?,
+            result: input.field("result")?,
+            max_geodes: input.field("max_geodes")?,
+            best_plan: read_plan(&input.field::<String>("best_plan")?)?,
+            worklist: input
+                .list("worklist")?
+                .into_iter()
+                .map(read_plan)
+                .collect::<Option<_>>()?,
+        })
+    }
+}
+
+fn search(
+    blueprints: &[Blueprint],
+    minutes: i64,
+    init: i64,
+    combine: fn(i64, &Blueprint, i64) -> i64,
+    control: &Control,
+) -> Result<i64, Cancelled> {
+    let total = blueprints.len() as u64;
+    let mut search = control.resume().unwrap_or_else(|| Search::new(init));
+    while let Some(blueprint) = blueprints.get(search.blueprint) {
+        control.report("blueprints", search.blueprint as u64, Some(total));
+        find_best_plan(blueprint, minutes, &mut search, control)?;
+        search = Search {
+            blueprint: search.blueprint + 1,
+            ..Search::new(combine(search.result, blueprint, search.max_geodes))
+        };
+    }
+    Ok(search.result)
+}
+
+pub fn part1(input: &str, control: &Control) -> Result<i64, Cancelled> {
+    let blueprints = parse_input(input).unwrap();
+    search(
+        &blueprints,
+        24,
+        0,
+        |res, blueprint, geodes| res + blueprint.id * geodes,
+        control,
+    )
+}
+
+pub fn part2(input: &str, control: &Control) -> Result<i64, Cancelled> {
+    let blueprints = parse_input(input).unwrap();
+    let blueprints = &blueprints[..blueprints.len().min(3)];
+    search(blueprints, 32, 1, |res, _, geodes| res * geodes, control)
+}
+
+pub fn solve1() {
+    let result = part1(&read_input(), &Control::default()).unwrap();
+    println!("result: {result}")
+}
+
+pub fn solve2() {
+    let result = part2(&read_input(), &Control::default()).unwrap();
+    println!("result: {result}")
+}
+
+/// Continues the plan search of `search` until its worklist is exhausted.
+fn find_best_plan(
+    blueprint: &Blueprint,
+    minutes: i64,
+    search: &mut Search,
+    control: &Control,
+) -> Result<(), Cancelled> {
+    loop {
+        control.check_with(search)?;
+        let Some(current) = search.worklist.pop() else {
+            return Ok(());
+        };
+        let res = Evaluator::new(blueprint.clone(), current.clone()).eval(minutes);
+        if !res.finished {
+            continue;
+        }
+        for next_resource in Resource::all().into_iter().filter(|r| !res.is_saturated(r)) {
+            let mut next_plan = current.clone();
+            next_plan.push(next_resource);
+            search.worklist.push(next_plan);
+        }
+        if res.produced_geodes > search.max_geodes {
+            search.max_geodes = res.produced_geodes;
+            search.best_plan = current;
+        }
+    }
+}
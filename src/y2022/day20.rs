@@ -1,14 +1,16 @@
 use std::cmp::Ordering;
-use std::fs;
 
-fn read_input() -> Vec<i64> {
-    let file_path = "inputs/day20.txt";
-    let input = fs::read_to_string(file_path).expect("Should have been able to read the file");
-    parse_input(input)
+use crate::input;
+use crate::parse::{self, ParseResult};
+
+pub const INPUT: &str = "inputs/2022/day20.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
 }
 
-fn parse_input(input: String) -> Vec<i64> {
-    input.lines().map(|l| l.parse().unwrap()).collect()
+pub(crate) fn parse_input(input: &str) -> ParseResult<Vec<i64>> {
+    parse::lines(input, parse::number)
 }
 
 fn materialize_numbers(numbers: &[i64], positions: &[usize]) -> Vec<i64> {
@@ -42,8 +44,8 @@ fn shift_pos(start_pos: i64, delta: i64, n: i64) -> i64 {
     new_pos
 }
 
-pub fn solve1() {
-    let numbers = read_input();
+pub fn part1(input: &str) -> i64 {
+    let numbers = parse_input(input).unwrap();
     let n = numbers.len();
     let mut positions: Vec<usize> = (0..n).collect();
 
@@ -67,15 +69,18 @@ pub fn solve1() {
     }
     let result_numbers = materialize_numbers(&numbers, &positions);
     let zero_idx = result_numbers.iter().position(|n| *n == 0).unwrap();
-    let result = dbg!(result_numbers[(zero_idx + 1000) % n])
+    dbg!(result_numbers[(zero_idx + 1000) % n])
         + dbg!(result_numbers[(zero_idx + 2000) % n])
-        + dbg!(result_numbers[(zero_idx + 3000) % n]);
-    println!("{result}");
+        + dbg!(result_numbers[(zero_idx + 3000) % n])
 }
 
-pub fn solve2() {
+pub fn part2(input: &str) -> i64 {
     let decryption_key = 811589153;
-    let numbers: Vec<i64> = read_input().iter().map(|n| n * decryption_key).collect();
+    let numbers: Vec<i64> = parse_input(input)
+        .unwrap()
+        .iter()
+        .map(|n| n * decryption_key)
+        .collect();
     let n = numbers.len();
     let mut positions: Vec<usize> = (0..n).collect();
 
@@ -101,8 +106,17 @@ pub fn solve2() {
     }
     let result_numbers = materialize_numbers(&numbers, &positions);
     let zero_idx = result_numbers.iter().position(|n| *n == 0).unwrap();
-    let result = dbg!(result_numbers[(zero_idx + 1000) % n])
+    dbg!(result_numbers[(zero_idx + 1000) % n])
         + dbg!(result_numbers[(zero_idx + 2000) % n])
-        + dbg!(result_numbers[(zero_idx + 3000) % n]);
+        + dbg!(result_numbers[(zero_idx + 3000) % n])
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input());
     println!("{result}");
 }
@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+
+use crate::input;
+use crate::inspect::Inspection;
+use crate::parse::{self, ParseResult};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Operator {
+    fn apply(&self, lhs: i64, rhs: i64) -> i64 {
+        match self {
+            Operator::Add => lhs + rhs,
+            Operator::Sub => lhs - rhs,
+            Operator::Mul => lhs * rhs,
+            Operator::Div => lhs / rhs,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Task {
+    Const(i64),
+    Bin(String, String, Operator),
+}
+
+type Monkeys = HashMap<String, Task>;
+
+#[derive(Debug, Clone)]
+enum Value {
+    Const(i64),
+    Humn,
+    Bin(Box<Value>, Box<Value>, Operator),
+}
+
+pub const INPUT: &str = "inputs/2022/day21.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+pub(crate) fn parse_input(input: &str) -> ParseResult<Monkeys> {
+    let tasks = parse::lines(input, |line| {
+        let (name, task) = parse::split_once(line, ": ")?;
+        let task = if let Some((l, r)) = task.split_once(" + ") {
+            Task::Bin(l.to_string(), r.to_string(), Operator::Add)
+        } else if let Some((l, r)) = task.split_once(" - ") {
+            Task::Bin(l.to_string(), r.to_string(), Operator::Sub)
+        } else if let Some((l, r)) = task.split_once(" * ") {
+            Task::Bin(l.to_string(), r.to_string(), Operator::Mul)
+        } else if let Some((l, r)) = task.split_once(" / ") {
+            Task::Bin(l.to_string(), r.to_string(), Operator::Div)
+        } else {
+            Task::Const(parse::number(task)?)
+        };
+        Ok((name.to_string(), task))
+    })?;
+    Ok(tasks.into_iter().collect())
+}
+
+fn simplify_task(monkeys: &mut Monkeys, task: String) -> Value {
+    if task == "humn" {
+        return Value::Humn;
+    }
+    match monkeys.get(&task).unwrap().clone() {
+        Task::Const(value) => Value::Const(value),
+        Task::Bin(l, r, op) => match (simplify_task(monkeys, l), simplify_task(monkeys, r)) {
+            (Value::Const(l), Value::Const(r)) => Value::Const(op.apply(l, r)),
+            (l, r) => Value::Bin(Box::from(l), Box::from(r), op),
+        },
+    }
+}
+
+fn solve_task(monkeys: &mut Monkeys, task: Task) -> i64 {
+    match task {
+        Task::Const(value) => value,
+        Task::Bin(l, r, op) => {
+            let l_task = monkeys.get(&l).unwrap();
+            let l_value = solve_task(monkeys, l_task.clone());
+            let l_mut = monkeys.get_mut(&l).unwrap();
+            *l_mut = Task::Const(l_value);
+
+            let r_task = monkeys.get(&r).unwrap();
+            let r_value = solve_task(monkeys, r_task.clone());
+            let r_mut = monkeys.get_mut(&r).unwrap();
+            *r_mut = Task::Const(r_value);
+
+            op.apply(l_value, r_value)
+        }
+    }
+}
+
+fn solve_for_humn(value: Value, result: i64) -> i64 {
+    match value {
+        Value::Humn => result,
+        Value::Bin(l, r, op) => match (*l, *r) {
+            (Value::Const(l), r) => match op {
+                Operator::Add => solve_for_humn(r, result - l),
+                Operator::Sub => solve_for_humn(r, -(result - l)),
+                Operator::Mul => solve_for_humn(r, result / l),
+                Operator::Div => solve_for_humn(r, l / result),
+            },
+            (l, Value::Const(r)) => match op {
+                Operator::Add => solve_for_humn(l, result - r),
+                Operator::Sub => solve_for_humn(l, result + r),
+                Operator::Mul => solve_for_humn(l, result / r),
+                Operator::Div => solve_for_humn(l, result * r),
+            },
+            _ => unreachable!(),
+        },
+        Value::Const(_) => unreachable!(),
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    let mut monkeys = parse_input(input).unwrap();
+    let root = monkeys.get("root").unwrap().clone();
+    solve_task(&mut monkeys, root)
+}
+
+pub fn part2(input: &str) -> i64 {
+    let mut monkeys = parse_input(input).unwrap();
+    let (l_val, r_val) = match monkeys.get("root").unwrap().clone() {
+        Task::Bin(l, r, _) => (
+            simplify_task(&mut monkeys, l),
+            simplify_task(&mut monkeys, r),
+        ),
+        Task::Const(_) => unreachable!(),
+    };
+
+    match r_val {
+        Value::Const(r) => solve_for_humn(l_val, r),
+        _ => panic!("expected humn on the left hand side of root"),
+    }
+}
+
+/// The length of the longest chain of monkeys from `name` down to one yelling a number, or
+/// `None` if the chain refers to a missing monkey or loops.
+fn depth(
+    monkeys: &Monkeys,
+    name: &str,
+    depths: &mut HashMap<String, Option<usize>>,
+) -> Option<usize> {
+    if let Some(depth) = depths.get(name) {
+        return *depth;
+    }
+    // Marks the monkey as being visited, so a loop ends up here and yields `None`.
+    depths.insert(name.to_string(), None);
+    let depth = match monkeys.get(name)? {
+        Task::Const(_) => Some(0),
+        Task::Bin(l, r, _) => {
+            let l = depth(monkeys, l, depths)?;
+            let r = depth(monkeys, r, depths)?;
+            Some(l.max(r) + 1)
+        }
+    };
+    depths.insert(name.to_string(), depth);
+    depth
+}
+
+fn depends_on_humn(monkeys: &Monkeys, name: &str) -> bool {
+    name == "humn"
+        || match monkeys.get(name) {
+            Some(Task::Bin(l, r, _)) => depends_on_humn(monkeys, l) || depends_on_humn(monkeys, r),
+            _ => false,
+        }
+}
+
+/// Part 2 solves for `humn` by undoing one operation at a time, which needs `humn` to appear
+/// exactly once, on the left of `root`.
+pub fn inspect(input: &str) -> Inspection {
+    let mut inspection = Inspection::default();
+    let monkeys = match parse_input(input) {
+        Ok(monkeys) => monkeys,
+        Err(e) => {
+            inspection.warn(format!("the input does not parse: {e}"));
+            return inspection;
+        }
+    };
+    let operations = monkeys
+        .values()
+        .filter(|t| matches!(t, Task::Bin(..)))
+        .count();
+    inspection.fact("monkeys", monkeys.len());
+    inspection.fact("yelling numbers", monkeys.len() - operations);
+    inspection.fact("doing operations", operations);
+
+    let Some(Task::Bin(l, r, _)) = monkeys.get("root") else {
+        inspection.warn("there is no monkey root doing an operation");
+        return inspection;
+    };
+    match depth(&monkeys, "root", &mut HashMap::new()) {
+        Some(depth) => inspection.fact("expression depth", depth),
+        None => {
+            inspection.warn("root depends on a missing monkey or on itself");
+            return inspection;
+        }
+    }
+    let humn_uses = monkeys
+        .values()
+        .filter(|t| matches!(t, Task::Bin(l, r, _) if l == "humn" || r == "humn"))
+        .count();
+    inspection.fact("uses of humn", humn_uses);
+    if humn_uses != 1 {
+        inspection.warn(format!("humn is used {humn_uses} times instead of once"));
+    }
+    match (depends_on_humn(&monkeys, l), depends_on_humn(&monkeys, r)) {
+        (true, false) => {}
+        (false, true) => inspection.warn("humn is on the right of root"),
+        (true, true) => inspection.warn("both sides of root depend on humn"),
+        (false, false) => inspection.warn("root does not depend on humn"),
+    }
+    inspection
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input());
+    println!("{result}")
+}
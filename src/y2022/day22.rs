@@ -1,11 +1,15 @@
 use std::collections::HashMap;
 use std::hash::Hash;
-use std::{fs, vec};
+use std::vec;
 
 use itertools::Itertools;
 
+use crate::input;
+use crate::inspect::Inspection;
+use crate::parse::{self, ParseError, ParseResult};
+
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
-struct Pos {
+pub(crate) struct Pos {
     x: i32,
     y: i32,
 }
@@ -22,7 +26,7 @@ impl Pos {
 type TraversalMap = HashMap<(Pos, Direction), (Pos, Direction)>;
 
 #[derive(Debug)]
-struct World {
+pub(crate) struct World {
     world: Vec<Vec<char>>,
 }
 
@@ -177,14 +181,14 @@ impl World {
 }
 
 #[derive(Debug, Copy, Clone)]
-enum Instr {
+pub(crate) enum Instr {
     Go(i32),
     Left,
     Right,
 }
 
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
-enum Direction {
+pub(crate) enum Direction {
     Left,
     Right,
     Top,
@@ -211,43 +215,49 @@ impl Direction {
     }
 }
 
-fn read_input() -> (World, Vec<Instr>) {
-    let file_path = "inputs/day22.txt";
-    let input = fs::read_to_string(file_path).expect("Should have been able to read the file");
-    parse_input(input)
+pub const INPUT: &str = "inputs/2022/day22.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
 }
 
-#[allow(unstable_name_collisions)]
-fn parse_input(input: String) -> (World, Vec<Instr>) {
-    let mut lines = input.lines();
+pub(crate) fn parse_input(input: &str) -> ParseResult<(World, Vec<Instr>)> {
+    let mut lines = input.lines().enumerate();
     let mut world = vec![];
 
-    for line in lines.by_ref() {
+    for (index, line) in lines.by_ref() {
         if line.is_empty() {
             break;
         }
+        if let Some(c) = line.chars().find(|c| !" .#".contains(*c)) {
+            let error = ParseError::new(format!("unexpected tile {c:?}"));
+            return Err(error.on_line(index + 1));
+        }
         world.push(line.chars().collect());
     }
 
-    let instrs = lines
-        .next()
-        .unwrap()
-        .split('L')
-        .map(|rs| {
-            rs.split('R')
-                .map(|d| Instr::Go(d.parse().unwrap()))
-                .intersperse(Instr::Right)
-                .collect::<Vec<_>>()
-        })
-        .intersperse(vec![Instr::Left])
-        .flatten()
-        .collect();
+    let Some((index, path)) = lines.next() else {
+        return Err(ParseError::new("expected the path below the map"));
+    };
+    let mut instrs = vec![];
+    for (i, rs) in path.split('L').enumerate() {
+        if i > 0 {
+            instrs.push(Instr::Left);
+        }
+        for (j, d) in rs.split('R').enumerate() {
+            if j > 0 {
+                instrs.push(Instr::Right);
+            }
+            let steps = parse::number(d).map_err(|e| e.on_line(index + 1))?;
+            instrs.push(Instr::Go(steps));
+        }
+    }
 
-    (World { world }, instrs)
+    Ok((World { world }, instrs))
 }
 
-pub fn solve1() {
-    let (world, instrs) = read_input();
+pub fn part1(input: &str) -> i32 {
+    let (world, instrs) = parse_input(input).unwrap();
     let mut current = world.find_leftmost(1);
     let mut direction = Direction::Right;
     for instr in instrs {
@@ -267,8 +277,7 @@ pub fn solve1() {
         Direction::Top => 3,
         Direction::Bot => 1,
     };
-    let result = 1000 * current.y + 4 * current.x + facing;
-    println!("{result}");
+    1000 * current.y + 4 * current.x + facing
 }
 
 fn range(from: Pos, to: Pos) -> Vec<Pos> {
@@ -298,12 +307,12 @@ fn add_edge(
     new_dir: Direction,
 ) {
     assert_eq!(from.len(), to.len());
-    for (from, to) in from.into_iter().zip(to.into_iter()) {
+    for (from, to) in from.into_iter().zip(to) {
         assert!(map.insert((from, old_dir), (to, new_dir)).is_none());
     }
 }
 
-pub fn solve2() {
+pub fn part2(input: &str) -> i32 {
     let mut traversal_map: TraversalMap = HashMap::new();
     add_edge(
         &mut traversal_map,
@@ -426,7 +435,7 @@ pub fn solve2() {
         );
     }
 
-    let (world, instrs) = read_input();
+    let (world, instrs) = parse_input(input).unwrap();
 
     let mut current = dbg!(world.find_leftmost(1));
     let mut direction = Direction::Right;
@@ -450,6 +459,64 @@ pub fn solve2() {
         Direction::Top => 3,
         Direction::Bot => 1,
     };
-    let result = 1000 * current.y + 4 * current.x + facing;
+    1000 * current.y + 4 * current.x + facing
+}
+
+/// The net part 2 hard-codes the edges for: which 50x50 squares of the map are faces.
+const NET: &str = ".##\n.#.\n##.\n#..";
+
+pub fn inspect(input: &str) -> Inspection {
+    let mut inspection = Inspection::default();
+    let (world, instrs) = match parse_input(input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            inspection.warn(format!("the input does not parse: {e}"));
+            return inspection;
+        }
+    };
+    let rows = &world.world;
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let tiles = rows.iter().flatten().filter(|c| **c != ' ').count();
+    let walls = rows.iter().flatten().filter(|c| **c == '#').count();
+    inspection.fact("map", format!("{width}x{}", rows.len()));
+    inspection.fact("tiles", tiles);
+    inspection.fact("walls", walls);
+    inspection.fact("instructions", instrs.len());
+
+    let size = ((tiles / 6) as f64).sqrt() as usize;
+    if size == 0 || size * size * 6 != tiles {
+        inspection.warn(format!("{tiles} tiles do not make six square faces"));
+        return inspection;
+    }
+    let net = (0..rows.len() / size)
+        .map(|y| {
+            (0..width / size)
+                .map(|x| match rows[y * size].get(x * size) {
+                    Some(' ') | None => '.',
+                    Some(_) => '#',
+                })
+                .collect::<String>()
+        })
+        .join("\n");
+    inspection.fact("face size", size);
+    inspection.fact("net", &net);
+    if net.matches('#').count() != 6 {
+        inspection.warn("the map is not cut into six faces along the face grid");
+    }
+    if size != 50 || net != NET {
+        inspection.warn(format!(
+            "part 2 only knows the edges of 50x50 faces laid out as\n{NET}"
+        ));
+    }
+    inspection
+}
+
+pub fn solve1() {
+    let result = part1(&read_input());
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input());
     println!("{result}");
 }
@@ -0,0 +1,292 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::control::{Cancelled, Control};
+use crate::events::Event;
+use crate::input;
+use crate::parse::{ParseError, ParseResult};
+use crate::simulation::{self, Simulation};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct Position {
+    x: i32,
+    y: i32,
+}
+
+impl Position {
+    fn new(x: i32, y: i32) -> Self {
+        Position { x, y }
+    }
+
+    fn neighbors(&self) -> [Position; 8] {
+        [
+            Position::new(self.x - 1, self.y - 1), // NW
+            Position::new(self.x, self.y - 1),     // N
+            Position::new(self.x + 1, self.y - 1), // NE
+            Position::new(self.x + 1, self.y),     // E
+            Position::new(self.x + 1, self.y + 1), // SE
+            Position::new(self.x, self.y + 1),     // S
+            Position::new(self.x - 1, self.y + 1), // SW
+            Position::new(self.x - 1, self.y),     // W
+        ]
+    }
+
+    fn shift(&self, dir: Direction) -> Position {
+        match dir {
+            N => Position::new(self.x, self.y - 1),
+            E => Position::new(self.x + 1, self.y),
+            S => Position::new(self.x, self.y + 1),
+            W => Position::new(self.x - 1, self.y),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Direction {
+    N,
+    S,
+    W,
+    E,
+}
+
+type World = HashSet<Position>;
+
+pub const INPUT: &str = "inputs/2022/day23.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+pub(crate) fn parse_input(input: &str) -> ParseResult<World> {
+    let mut world = HashSet::new();
+    for (y, line) in input::lines(input).enumerate() {
+        for (x, char) in line.chars().enumerate() {
+            match char {
+                '#' => {
+                    world.insert(Position {
+                        x: x as i32,
+                        y: y as i32,
+                    });
+                }
+                '.' => {}
+                _ => {
+                    let error = ParseError::new(format!("unexpected tile {char:?}"));
+                    return Err(error.on_line(y + 1));
+                }
+            }
+        }
+    }
+    Ok(world)
+}
+
+use Direction::*;
+
+/// The elves spreading out, one step being one round of proposing and moving.
+struct Grove {
+    world: World,
+    direction_index: usize,
+    rounds: usize,
+    moved: bool,
+    /// The elves that moved in the last round, from where to where.
+    moves: Vec<(Position, Position)>,
+}
+
+impl Grove {
+    fn new(world: World) -> Self {
+        Grove {
+            world,
+            direction_index: 0,
+            rounds: 0,
+            moved: true,
+            moves: vec![],
+        }
+    }
+
+    fn empty_ground(&self) -> i32 {
+        let mut max_x = i32::MIN;
+        let mut min_x = i32::MAX;
+        let mut max_y = i32::MIN;
+        let mut min_y = i32::MAX;
+        for ele in self.world.iter() {
+            max_x = max_x.max(ele.x);
+            min_x = min_x.min(ele.x);
+
+            max_y = max_y.max(ele.y);
+            min_y = min_y.min(ele.y);
+        }
+
+        (max_x - min_x + 1) * (max_y - min_y + 1) - self.world.len() as i32
+    }
+}
+
+impl Simulation for Grove {
+    /// The positions of all elves, in order.
+    type Snapshot = Vec<(i32, i32)>;
+
+    fn step(&mut self) {
+        let world = &mut self.world;
+        let directions: [Direction; 4] = [N, S, W, E];
+        let elf_count = world.len();
+        self.rounds += 1;
+        // Calculate moves
+        let mut moves: HashMap<Position, Vec<Position>> = HashMap::new();
+
+        for elf in world.iter() {
+            let neighbors = elf.neighbors();
+            // Are there no neighbors?
+            let mut has_neighbor = false;
+            for neighbor in neighbors.iter() {
+                if world.contains(neighbor) {
+                    has_neighbor = true;
+                }
+            }
+            if !has_neighbor {
+                assert!(moves.insert(*elf, vec![*elf]).is_none());
+                continue;
+            }
+
+            let mut has_stepped = false;
+            // Try move rules in order
+            for i in 0..4 {
+                match directions[(self.direction_index + i) % 4] {
+                    N => {
+                        if neighbors[0..3].iter().all(|n| !world.contains(n)) {
+                            moves
+                                .entry(elf.shift(N))
+                                .and_modify(|positions| positions.push(*elf))
+                                .or_insert_with(|| vec![*elf]);
+                            has_stepped = true;
+                            break;
+                        }
+                    }
+                    S => {
+                        if neighbors[4..7].iter().all(|n| !world.contains(n)) {
+                            moves
+                                .entry(elf.shift(S))
+                                .and_modify(|positions| positions.push(*elf))
+                                .or_insert_with(|| vec![*elf]);
+                            has_stepped = true;
+                            break;
+                        }
+                    }
+                    E => {
+                        if neighbors[2..5].iter().all(|n| !world.contains(n)) {
+                            moves
+                                .entry(elf.shift(E))
+                                .and_modify(|positions| positions.push(*elf))
+                                .or_insert_with(|| vec![*elf]);
+                            has_stepped = true;
+                            break;
+                        }
+                    }
+                    W => {
+                        if [neighbors[6], neighbors[7], neighbors[0]]
+                            .iter()
+                            .all(|n| !world.contains(n))
+                        {
+                            moves
+                                .entry(elf.shift(W))
+                                .and_modify(|positions| positions.push(*elf))
+                                .or_insert_with(|| vec![*elf]);
+                            has_stepped = true;
+                            break;
+                        }
+                    }
+                }
+            }
+            if !has_stepped {
+                assert!(moves.insert(*elf, vec![*elf]).is_none());
+            }
+        }
+
+        // Actually step
+        world.clear();
+        self.moved = false;
+        self.moves.clear();
+        for (pos, previous) in moves {
+            if previous.len() > 1 {
+                self.moved = true;
+                for p in previous {
+                    assert!(world.insert(p))
+                }
+            } else {
+                if pos != previous[0] {
+                    self.moved = true;
+                    self.moves.push((previous[0], pos));
+                }
+                assert!(world.insert(pos))
+            }
+        }
+        assert_eq!(world.len(), elf_count);
+
+        self.direction_index = (self.direction_index + 1) % 4;
+    }
+
+    fn is_done(&self) -> bool {
+        !self.moved
+    }
+
+    fn snapshot(&self) -> Self::Snapshot {
+        let mut elves: Vec<_> = self.world.iter().map(|p| (p.x, p.y)).collect();
+        elves.sort();
+        elves
+    }
+
+    fn events(&self) -> Vec<Event> {
+        self.moves
+            .iter()
+            .map(|(from, to)| {
+                Event::new("elf_moved")
+                    .with("round", self.rounds)
+                    .with("from", vec![from.x, from.y])
+                    .with("to", vec![to.x, to.y])
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Grove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (xs, ys): (Vec<_>, Vec<_>) = self.world.iter().map(|p| (p.x, p.y)).unzip();
+        let (Some(min_x), Some(max_x)) = (xs.iter().min(), xs.iter().max()) else {
+            return Ok(());
+        };
+        let (min_y, max_y) = (ys.iter().min().unwrap(), ys.iter().max().unwrap());
+        for y in *min_y..=*max_y {
+            if y > *min_y {
+                writeln!(f)?;
+            }
+            for x in *min_x..=*max_x {
+                let c = if self.world.contains(&Position::new(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                };
+                write!(f, "{c}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn part1(input: &str, control: &Control) -> Result<i32, Cancelled> {
+    let mut grove = Grove::new(parse_input(input).unwrap());
+    simulation::run_controlled(&mut grove, Some(10), control, "rounds")?;
+    Ok(grove.empty_ground())
+}
+
+pub fn part2(input: &str, control: &Control) -> Result<i32, Cancelled> {
+    let mut grove = Grove::new(parse_input(input).unwrap());
+    let rounds = simulation::run_controlled(&mut grove, None, control, "rounds")?;
+    Ok(rounds as i32)
+}
+
+pub fn solve1() {
+    let result = part1(&read_input(), &Control::default()).unwrap();
+    println!("{result}")
+}
+
+pub fn solve2() {
+    let result = part2(&read_input(), &Control::default()).unwrap();
+    println!("{result}")
+}
@@ -0,0 +1,468 @@
+use itertools::Itertools;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+use std::fmt;
+use std::mem;
+use Direction::*;
+
+use crate::checkpoint::{Checkpoint, Reader, Writer};
+use crate::control::{Cancelled, Control};
+use crate::input;
+use crate::inspect::Inspection;
+use crate::parse::{ParseError, ParseResult};
+use crate::simulation::Simulation;
+
+const X: usize = 100;
+const Y: usize = 35;
+const T: usize = X * Y;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Direction {
+    N,
+    S,
+    W,
+    E,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            N => '^',
+            S => 'v',
+            W => '<',
+            E => '>',
+        };
+        write!(f, "{c}")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+impl Position {
+    fn new(x: usize, y: usize) -> Self {
+        Position {
+            x: x.rem_euclid(X),
+            y: y.rem_euclid(Y),
+        }
+    }
+
+    fn shift(&self, dir: Direction) -> Position {
+        match dir {
+            N => Position::new(self.x, self.y + Y - 1),
+            E => Position::new(self.x + 1, self.y),
+            S => Position::new(self.x, self.y + 1),
+            W => Position::new(self.x + X - 1, self.y),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Field(Vec<Direction>);
+
+impl Field {
+    fn add(&mut self, dir: Direction) {
+        self.0.push(dir)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct World(Vec<Field>);
+
+impl fmt::Display for World {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", "#".repeat(X + 1))?;
+        for (index, field) in self.0.iter().enumerate() {
+            if index % X == 0 {
+                write!(f, "#\n#")?;
+            }
+            match field.0.len() {
+                0 => write!(f, ".")?,
+                1 => write!(f, "{}", field.0[0])?,
+                x => write!(f, "{x}")?,
+            }
+        }
+        write!(f, "#\n{}", "#".repeat(X + 2))?;
+        Ok(())
+    }
+}
+
+impl World {
+    fn new() -> World {
+        let mut fields = Vec::with_capacity(X * Y);
+        for _ in 0..(X * Y) {
+            fields.push(Field(vec![]))
+        }
+
+        World(fields)
+    }
+
+    fn get(&self, position: Position) -> &Field {
+        self.0.get(position.y * X + position.x).unwrap()
+    }
+
+    fn get_mut(&mut self, position: Position) -> &mut Field {
+        self.0.get_mut(position.y * X + position.x).unwrap()
+    }
+
+    fn add(&mut self, position: Position, dir: Direction) {
+        self.get_mut(position).add(dir)
+    }
+
+    fn clear(&mut self) {
+        for ele in self.0.iter_mut() {
+            ele.0.clear()
+        }
+    }
+
+    fn evolve(&self, next_world: &mut World) {
+        for (index, field) in self.0.iter().enumerate() {
+            let position = Position::new(index % X, index / X);
+            for blizzard in field.0.iter() {
+                next_world.add(position.shift(*blizzard), *blizzard)
+            }
+        }
+    }
+}
+
+struct BakedWorld(Vec<[[bool; Y]; X]>);
+
+impl BakedWorld {
+    fn new() -> BakedWorld {
+        let mut result = Vec::with_capacity(T);
+        for _ in 0..T {
+            result.push([[false; Y]; X])
+        }
+        BakedWorld(result)
+    }
+
+    fn set_occupied(&mut self, pos: Position, t: usize) {
+        let board = self.0.get_mut(t % T).unwrap();
+        let row = board.get_mut(pos.x).unwrap();
+        let col = row.get_mut(pos.y).unwrap();
+        *col = true
+    }
+
+    fn is_occupied(&self, pos: Position, t: usize) -> bool {
+        self.0[t % T][pos.x][pos.y]
+    }
+}
+
+/// The blizzards moving through the valley, one step being one minute.
+struct Valley {
+    world: World,
+    backbuffer: World,
+}
+
+impl Valley {
+    fn new(world: World) -> Self {
+        Valley {
+            world,
+            backbuffer: World::new(),
+        }
+    }
+}
+
+impl Simulation for Valley {
+    type Snapshot = World;
+
+    fn step(&mut self) {
+        self.backbuffer.clear();
+        self.world.evolve(&mut self.backbuffer);
+        mem::swap(&mut self.world, &mut self.backbuffer);
+    }
+
+    fn snapshot(&self) -> World {
+        self.world.clone()
+    }
+}
+
+impl fmt::Display for Valley {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.world)
+    }
+}
+
+fn bake_world(world: World) -> BakedWorld {
+    let mut valley = Valley::new(world);
+    let mut baked_world = BakedWorld::new();
+
+    for t in 0..T {
+        for x in 0..X {
+            for y in 0..Y {
+                let pos = Position { x, y };
+                let field = valley.world.get(pos);
+                if !field.is_empty() {
+                    baked_world.set_occupied(pos, t)
+                }
+            }
+        }
+        valley.step();
+    }
+    baked_world
+}
+
+pub const INPUT: &str = "inputs/2022/day24.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+pub(crate) fn parse_input(input: &str) -> ParseResult<World> {
+    let mut fields = vec![];
+    for line in input.lines().dropping(1).dropping_back(1) {
+        for char in line.chars() {
+            if char == '#' {
+                continue;
+            }
+            match char {
+                '.' => fields.push(Field(vec![])),
+                '<' => fields.push(Field(vec![W])),
+                '>' => fields.push(Field(vec![E])),
+                '^' => fields.push(Field(vec![N])),
+                'v' => fields.push(Field(vec![S])),
+                x => return Err(ParseError::new(format!("unknown character {x:?}"))),
+            }
+        }
+    }
+    Ok(World(fields))
+}
+
+/// State of the search through the valley: the candidate moves still to try, the ones already
+/// tried, the targets left to reach and the time the last target was reached.
+struct Search {
+    candidates: BinaryHeap<Reverse<(usize, Position)>>,
+    visited: HashSet<(usize, Position)>,
+    targets: Vec<Position>,
+    arrival: usize,
+}
+
+impl Search {
+    fn new(baked_world: &BakedWorld, targets: Vec<Position>) -> Self {
+        let mut candidates = BinaryHeap::new();
+        for t in 1..20 {
+            if !baked_world.is_occupied(Position::new(0, 0), t) {
+                candidates.push(Reverse((t, Position::new(0, 0))));
+            }
+        }
+        Search {
+            candidates,
+            visited: HashSet::new(),
+            targets,
+            arrival: 0,
+        }
+    }
+}
+
+fn write_position((t, pos): &(usize, Position)) -> String {
+    format!("{t} {} {}", pos.x, pos.y)
+}
+
+fn read_position(line: &str) -> Option<(usize, Position)> {
+    let mut numbers = line.split(' ').map(|n| n.parse::<usize>().ok());
+    let (t, x, y) = (numbers.next()??, numbers.next()??, numbers.next()??);
+    Some((t, Position::new(x, y)))
+}
+
+impl Checkpoint for Search {
+    fn write(&self, out: &mut Writer) {
+        out.field("arrival", self.arrival);
+        out.list(
+            "targets",
+            self.targets.iter().map(|p| write_position(&(0, *p))),
+        );
+        out.list(
+            "candidates",
+            self.candidates.iter().map(|Reverse(c)| write_position(c)),
+        );
+        // Candidates are tried in order of time, so visits before the earliest candidate can
+        // never be hit again and need not be saved.
+        let earliest = self.candidates.peek().map_or(0, |Reverse((t, _))| *t);
+        let visited: Vec<_> = self
+            .visited
+            .iter()
+            .filter(|(t, _)| *t >= earliest)
+            .map(write_position)
+            .collect();
+        out.list("visited", visited.into_iter());
+    }
+
+    fn read(input: &mut Reader) -> Option<Self> {
+        Some(Search {
+            arrival: input.field("arrival")?,
+            targets: input
+                .list("targets")?
+                .into_iter()
+                .map(|l| read_position(l).map(|(_, p)| p))
+                .collect::<Option<_>>()?,
+            candidates: input
+                .list("candidates")?
+                .into_iter()
+                .map(|l| read_position(l).map(Reverse))
+                .collect::<Option<_>>()?,
+            visited: input
+                .list("visited")?
+                .into_iter()
+                .map(read_position)
+                .collect::<Option<_>>()?,
+        })
+    }
+}
+
+/// Walks to every target in turn, returning the minute the last one is reached.
+fn find_way(
+    baked_world: &BakedWorld,
+    mut search: Search,
+    control: &Control,
+) -> Result<usize, Cancelled> {
+    let mut minute = 0;
+    while let Some(&target) = search.targets.last() {
+        control.check_with(&search)?;
+        let Some(Reverse((t, pos))) = search.candidates.pop() else {
+            panic!("no path to the target found")
+        };
+        if !search.visited.insert((t, pos)) {
+            continue;
+        }
+        if t > minute {
+            minute = t;
+            control.report("minutes", t as u64, None);
+        }
+        if pos == target {
+            search.arrival = t + 1;
+            search.targets.pop();
+            search.candidates.clear();
+
+            for t in t + 2..t + 22 {
+                if !baked_world.is_occupied(target, t) {
+                    search.candidates.push(Reverse((t, target)));
+                }
+            }
+            continue;
+        }
+
+        let candidates = &mut search.candidates;
+        if pos.y != Y - 1 && !baked_world.is_occupied(pos.shift(S), t + 1) {
+            candidates.push(Reverse((t + 1, pos.shift(S))))
+        }
+        if pos.x != X - 1 && !baked_world.is_occupied(pos.shift(E), t + 1) {
+            candidates.push(Reverse((t + 1, pos.shift(E))))
+        }
+        if pos.y != 0 && !baked_world.is_occupied(pos.shift(N), t + 1) {
+            candidates.push(Reverse((t + 1, pos.shift(N))))
+        }
+        if pos.x != 0 && !baked_world.is_occupied(pos.shift(W), t + 1) {
+            candidates.push(Reverse((t + 1, pos.shift(W))))
+        }
+        if !baked_world.is_occupied(pos, t + 1) {
+            candidates.push(Reverse((t + 1, pos)))
+        }
+    }
+    Ok(search.arrival)
+}
+
+pub fn part1(input: &str, control: &Control) -> Result<usize, Cancelled> {
+    let baked_world = bake_world(parse_input(input).unwrap());
+    let search = control
+        .resume()
+        .unwrap_or_else(|| Search::new(&baked_world, vec![Position::new(X - 1, Y - 1)]));
+    find_way(&baked_world, search, control)
+}
+
+pub fn part2(input: &str, control: &Control) -> Result<usize, Cancelled> {
+    let baked_world = bake_world(parse_input(input).unwrap());
+    let targets = vec![
+        Position::new(X - 1, Y - 1),
+        Position::new(0, 0),
+        Position::new(X - 1, Y - 1),
+    ];
+    let search = control
+        .resume()
+        .unwrap_or_else(|| Search::new(&baked_world, targets));
+    find_way(&baked_world, search, control)
+}
+
+/// The valley is baked into arrays sized by `X` and `Y`, and blizzards wrap around inside the
+/// walls, so an input has to match both.
+pub fn inspect(input: &str) -> Inspection {
+    let mut inspection = Inspection::default();
+    let lines: Vec<&str> = input::lines(input).collect();
+    let (Some(first), Some(last)) = (lines.first(), lines.last()) else {
+        inspection.warn("the input is empty");
+        return inspection;
+    };
+    let width = first.len().saturating_sub(2);
+    let height = lines.len().saturating_sub(2);
+    inspection.fact("valley", format!("{width}x{height}"));
+    inspection.fact("solver expects", format!("{X}x{Y}"));
+    if (width, height) != (X, Y) {
+        inspection.warn(format!(
+            "the valley is {width}x{height}, but X and Y are {X}x{Y}"
+        ));
+    }
+    if lines.iter().any(|l| l.len() != first.len()) {
+        inspection.warn("not all rows have the same length");
+    }
+    if first.find('.') != Some(1) || last.rfind('.') != Some(last.len().saturating_sub(2)) {
+        inspection.warn("the entrance is not top left or the exit is not bottom right");
+    }
+
+    let inner = &lines[1.min(lines.len())..lines.len().saturating_sub(1)];
+    let count = |c: char| {
+        inner
+            .iter()
+            .flat_map(|l| l.chars())
+            .filter(|x| *x == c)
+            .count()
+    };
+    inspection.fact(
+        "blizzards",
+        format!(
+            "{} ^, {} v, {} <, {} >",
+            count('^'),
+            count('v'),
+            count('<'),
+            count('>')
+        ),
+    );
+    let unknown: Vec<char> = inner
+        .iter()
+        .flat_map(|l| l.chars())
+        .filter(|c| !"#.^v<>".contains(*c))
+        .unique()
+        .collect();
+    if !unknown.is_empty() {
+        inspection.warn(format!("unknown characters {unknown:?}"));
+        return inspection;
+    }
+    let vertical_at = |column: usize| {
+        inner
+            .iter()
+            .any(|l| matches!(l.chars().nth(column), Some('^' | 'v')))
+    };
+    if vertical_at(1) || vertical_at(first.len().saturating_sub(2)) {
+        inspection.warn("blizzards move vertically in the column of the entrance or the exit");
+    }
+    if parse_input(input).is_ok_and(|world| world.0.len() != width * height) {
+        inspection.warn("the walls do not enclose a rectangle");
+    }
+    inspection
+}
+
+pub fn solve1() {
+    let result = part1(&read_input(), &Control::default()).unwrap();
+    println!("{result}");
+}
+
+pub fn solve2() {
+    let result = part2(&read_input(), &Control::default()).unwrap();
+    println!("{result}");
+}
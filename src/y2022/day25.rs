@@ -1,28 +1,33 @@
-use std::fs;
+use crate::input;
+use crate::parse::{self, ParseError, ParseResult};
 
-#[derive(Debug, Clone, Copy)]
-struct Snafu(pub i64);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snafu(pub i64);
 
 impl Snafu {
-    fn parse(line: &str) -> Snafu {
-        let mut current_place = 1;
+    pub fn parse(line: &str) -> ParseResult<Snafu> {
+        if line.is_empty() {
+            return Err(ParseError::new("expected a snafu number"));
+        }
         let mut result = 0i64;
-        for char in line.chars().rev() {
-            let modify = match char {
+        for char in line.chars() {
+            let digit = match char {
                 '0' => 0,
-                '1' => current_place,
-                '2' => current_place * 2,
-                '-' => -current_place,
-                '=' => -(current_place * 2),
-                _ => unreachable!(),
+                '1' => 1,
+                '2' => 2,
+                '-' => -1,
+                '=' => -2,
+                _ => return Err(ParseError::new(format!("invalid snafu digit {char:?}"))),
             };
-            result += modify;
-            current_place *= 5;
+            result = result
+                .checked_mul(5)
+                .and_then(|r| r.checked_add(digit))
+                .ok_or_else(|| ParseError::new(format!("snafu number {line:?} is too large")))?;
         }
-        Snafu(result)
+        Ok(Snafu(result))
     }
 
-    fn print(&self) -> String {
+    pub fn print(&self) -> String {
         let mut res = String::new();
         let mut current = self.0;
         let mut carry = 0;
@@ -64,28 +69,28 @@ impl Snafu {
     }
 }
 
-fn read_input() -> Vec<Snafu> {
-    let file_path = "inputs/day25.txt";
-    let input = fs::read_to_string(file_path).expect("Should have been able to read the file");
-    parse_input(input)
+pub const INPUT: &str = "inputs/2022/day25.txt";
+
+fn read_input() -> String {
+    input::load(INPUT)
+}
+
+pub(crate) fn parse_input(input: &str) -> ParseResult<Vec<Snafu>> {
+    parse::lines(input, Snafu::parse)
 }
 
-fn parse_input(input: String) -> Vec<Snafu> {
-    let mut snafus = vec![];
-    for line in input.lines() {
-        snafus.push(Snafu::parse(line));
-    }
-    snafus
+pub fn part1(input: &str) -> String {
+    Snafu(parse_input(input).unwrap().iter().map(|s| s.0).sum()).print()
 }
 
 pub fn solve1() {
-    let result: String = Snafu(read_input().iter().map(|s| s.0).sum()).print();
+    let result = part1(&read_input());
     println!("{result}");
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::day25::*;
+    use crate::y2022::day25::*;
 
     #[test]
     fn it_works() {
@@ -115,7 +120,7 @@ mod tests {
         ];
 
         for (expected, input) in test_cases {
-            let snafu = Snafu::parse(input);
+            let snafu = Snafu::parse(input).unwrap();
             assert_eq!(expected, snafu.0);
             assert_eq!(input, &snafu.print());
         }
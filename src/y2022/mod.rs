@@ -0,0 +1,109 @@
+//! Advent of Code 2022. The inputs live in `inputs/2022`.
+use crate::inspect::Inspection;
+use crate::parse::{ParseError, ParseResult};
+use crate::solver::{day, Puzzle};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub const YEAR: u16 = 2022;
+
+pub static PUZZLES: &[Puzzle] = &[
+    day!(YEAR, 1, day01, [part1, part2], version 2),
+    day!(YEAR, 2, day02, [part1, part2], version 2),
+    day!(YEAR, 3, day03, [part1, part2], version 2),
+    day!(YEAR, 4, day04, [part1, part2, part3], version 2),
+    day!(YEAR, 5, day05, [part1, part2], version 3),
+    day!(YEAR, 6, day06, [part1, part2], version 2),
+    day!(YEAR, 7, day07, [part1, part2]),
+    day!(YEAR, 8, day08, [part1, part2]),
+    day!(YEAR, 9, day09, [part1, part2]),
+    day!(YEAR, 10, day10, [part1, part2]),
+    day!(YEAR, 11, day11, controlled [part1, part2]),
+    day!(YEAR, 12, day12, [part1, part2]),
+    day!(YEAR, 13, day13, [part1, part2]),
+    day!(YEAR, 14, day14, [part1, part2]),
+    day!(YEAR, 15, day15, [part1, part2], version 2),
+    day!(YEAR, 16, day16, controlled [part1, part2]),
+    day!(YEAR, 17, day17, controlled [part1, part2], version 2),
+    day!(YEAR, 18, day18, [part1, part2]),
+    day!(YEAR, 19, day19, controlled [part1, part2]),
+    day!(YEAR, 20, day20, [part1, part2]),
+    day!(YEAR, 21, day21, [part1, part2]),
+    day!(YEAR, 22, day22, [part1, part2]),
+    day!(YEAR, 23, day23, controlled [part1, part2]),
+    day!(YEAR, 24, day24, controlled [part1, part2]),
+    day!(YEAR, 25, day25, [part1]),
+];
+
+/// Runs the parser of `day` on `input`, discarding what it parsed.
+pub fn check(day: u8, input: &str) -> ParseResult<()> {
+    match day {
+        1 => day01::parse_input(input).map(drop),
+        2 => day02::parse_input(input).map(drop),
+        3 => day03::parse_input(input).map(drop),
+        4 => day04::parse_input(input).map(drop),
+        5 => day05::parse_input(input).map(drop),
+        6 => day06::parse_input(input).map(drop),
+        7 => day07::parse_input(input).map(drop),
+        8 => day08::parse_input(input).map(drop),
+        9 => day09::parse_input(input).map(drop),
+        10 => day10::parse_input(input).map(drop),
+        11 => day11::parse_input(input).map(drop),
+        12 => day12::parse_input(input).map(drop),
+        13 => day13::parse_input(input).map(drop),
+        14 => day14::parse_input(input).map(drop),
+        15 => day15::parse_input(input).map(drop),
+        16 => day16::parse_input(input).map(drop),
+        17 => day17::parse_input(input).map(drop),
+        18 => day18::parse_input(input).map(drop),
+        19 => day19::parse_input(input).map(drop),
+        20 => day20::parse_input(input).map(drop),
+        21 => day21::parse_input(input).map(drop),
+        22 => day22::parse_input(input).map(drop),
+        23 => day23::parse_input(input).map(drop),
+        24 => day24::parse_input(input).map(drop),
+        25 => day25::parse_input(input).map(drop),
+        _ => Err(ParseError::new(format!("there is no day {day} in {YEAR}"))),
+    }
+}
+
+/// The inspection of a day: the shape of the input its solver depends on, or a report analysing
+/// the input, like the calorie statistics of day 1 or the stack drawings of day 5.
+pub fn inspector(day: u8) -> Option<fn(&str) -> Inspection> {
+    match day {
+        1 => Some(day01::inspect),
+        2 => Some(day02::inspect),
+        3 => Some(day03::inspect),
+        4 => Some(day04::inspect),
+        5 => Some(day05::inspect),
+        16 => Some(day16::inspect),
+        21 => Some(day21::inspect),
+        22 => Some(day22::inspect),
+        24 => Some(day24::inspect),
+        _ => None,
+    }
+}